@@ -0,0 +1,338 @@
+use crate::{Point, LineString, Polygon, MultiPolygon, segment};
+use crate::overlay::{union_all, node_self};
+use crate::relate::signed_area;
+use bbox_2d::MBR;
+use std::f64::consts::PI;
+
+//mitre length over buffer distance beyond which a mitre join is bevelled
+const MITRE_LIMIT: f64 = 5.0;
+
+///Shape of the corner where two offset segments meet
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum JoinStyle {
+    Round,
+    Mitre,
+    Bevel,
+}
+
+///Shape of the ends of a buffered line
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CapStyle {
+    Round,
+    Flat,
+    Square,
+}
+
+///Buffer parameters
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BufferParams {
+    ///Number of segments approximating a quarter circle
+    pub quadrant_segments: usize,
+    pub join: JoinStyle,
+    pub cap: CapStyle,
+}
+
+impl Default for BufferParams {
+    fn default() -> Self {
+        BufferParams { quadrant_segments: 8, join: JoinStyle::Round, cap: CapStyle::Round }
+    }
+}
+
+///Buffer of a point, empty for a flat cap or a non positive distance
+pub fn buffer_point(pt: &Point, distance: f64, params: &BufferParams) -> MultiPolygon {
+    MultiPolygon(point_pieces(pt, distance, params))
+}
+
+fn point_pieces(pt: &Point, distance: f64, params: &BufferParams) -> Vec<Polygon> {
+    if distance <= 0.0 {
+        return vec![];
+    }
+    match params.cap {
+        CapStyle::Round => {
+            let n = 4 * params.quadrant_segments.max(1);
+            let coords = (0..n).map(|k| {
+                let a = 2.0 * PI * (k as f64) / (n as f64);
+                Point::new(pt.x + distance * a.cos(), pt.y + distance * a.sin())
+            }).collect();
+            vec![Polygon::new(vec![coords])]
+        }
+        CapStyle::Square => {
+            let (x0, y0, x1, y1) = (pt.x - distance, pt.y - distance, pt.x + distance, pt.y + distance);
+            vec![Polygon::new(vec![vec![
+                Point::new(x0, y0), Point::new(x1, y0), Point::new(x1, y1), Point::new(x0, y1),
+            ]])]
+        }
+        CapStyle::Flat => vec![],
+    }
+}
+
+///Buffer of a line, a closed line gets joins all round and no caps ;
+///empty for a non positive distance
+pub fn buffer_line(coords: &[Point], distance: f64, params: &BufferParams) -> MultiPolygon {
+    let mut coords = coords.to_vec();
+    coords.dedup_by(|p, q| p.equals(q));
+    if coords.len() < 2 {
+        return match coords.first() {
+            Some(pt) => buffer_point(pt, distance, params),
+            None => MultiPolygon(vec![]),
+        };
+    }
+    if distance <= 0.0 {
+        return MultiPolygon(vec![]);
+    }
+    MultiPolygon(union_all(line_pieces(&coords, distance, params)))
+}
+
+///Buffer of a polygon, a negative distance erodes it and may leave nothing
+pub fn buffer_polygon(ply: &Polygon, distance: f64, params: &BufferParams) -> MultiPolygon {
+    MultiPolygon(polygon_pieces(ply, distance, params))
+}
+
+fn polygon_pieces(ply: &Polygon, distance: f64, params: &BufferParams) -> Vec<Polygon> {
+    if distance == 0.0 {
+        return vec![ply.clone()];
+    }
+    let mut pieces = vec![];
+    for ring in ply.0.iter() {
+        let mut coords = ring.coordinates().clone();
+        coords.dedup_by(|p, q| p.equals(q));
+        if coords.len() > 1 {
+            pieces.extend(line_pieces(&coords, distance.abs(), params));
+        }
+    }
+    if distance > 0.0 {
+        pieces.push(ply.clone());
+        return union_all(pieces);
+    }
+    let mut result = vec![ply.clone()];
+    for border in union_all(pieces).iter() {
+        result = result.iter().flat_map(|p| p.difference(border)).collect();
+    }
+    result
+}
+
+///Curve at distance from a line on one side, left of the line for a positive
+///distance, right for a negative one. Parts of the raw offset closer to some other
+///part of the line than to the segment or vertex they come from are loops and are
+///dropped, the rest is returned as connected pieces.
+pub fn offset_curve(coords: &[Point], distance: f64, join: JoinStyle, quadrant_segments: usize) -> Vec<LineString> {
+    let mut coords = coords.to_vec();
+    coords.dedup_by(|p, q| p.equals(q));
+    if coords.len() < 2 {
+        return vec![];
+    }
+    if distance == 0.0 {
+        return vec![LineString::new(coords)];
+    }
+    let (side, d) = (distance.signum(), distance.abs());
+    let n = coords.len();
+    let closed = n > 3 && coords[0].equals(&coords[n - 1]);
+    let normals: Vec<Point> = coords.windows(2).map(|w| normal(&w[0], &w[1], d)).collect();
+
+    let mut raw = vec![];
+    let turn = |raw: &mut Vec<(Point, Source)>, i: usize, j: usize| {
+        let (p, v, q) = (&coords[i], &coords[j], &coords[j + 1]);
+        let (u1, u2) = (Point::new(v.x - p.x, v.y - p.y), Point::new(q.x - v.x, q.y - v.y));
+        let cross = u1.x * u2.y - u1.y * u2.x;
+        let dot = u1.x * u2.x + u1.y * u2.y;
+        let (o1, o2) = (offset(v, &normals[i], side), offset(v, &normals[j], side));
+        //a left turn opens on the right, a reversal opens on both sides
+        let outer = side * cross < 0.0 || (cross == 0.0 && dot < 0.0);
+        raw.push((o1, if outer { Source::Vertex(j) } else { Source::Inner }));
+        if outer {
+            let sweep = -side * cross.abs().atan2(dot);
+            for pt in corner(v, &o1, &o2, sweep, d, join, quadrant_segments) {
+                raw.push((pt, Source::Vertex(j)));
+            }
+        }
+        raw.push((o2, Source::Segment(j)));
+    };
+    raw.push((offset(&coords[0], &normals[0], side), Source::Segment(0)));
+    for j in 1..n - 1 {
+        turn(&mut raw, j - 1, j);
+    }
+    if closed {
+        //the join at the first vertex ends where the curve started
+        turn(&mut raw, n - 2, 0);
+    } else {
+        raw.push((offset(&coords[n - 1], &normals[n - 2], side), Source::Segment(n - 2)));
+    }
+    //a repeated point starts what the later one starts
+    raw.dedup_by(|p, q| p.0.equals(&q.0) && {
+        q.1 = p.1;
+        true
+    });
+    if raw.len() < 2 {
+        return vec![];
+    }
+    let mut parts = trim_loops(&coords, &raw, d);
+    if closed && parts.len() > 1 && parts[parts.len() - 1].last() == parts[0].first() {
+        let mut last = parts.pop().unwrap();
+        last.extend(parts[0].drain(..).skip(1));
+        parts[0] = last;
+    }
+    parts.into_iter().map(LineString::new).collect()
+}
+
+//what a raw offset segment starting at a point was generated from
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Source {
+    Segment(usize),
+    Vertex(usize),
+    ///chord across the inner side of a turn, never on the offset curve
+    Inner,
+}
+
+//split the raw offset at its self intersections and keep pieces no closer to the line than d
+fn trim_loops(coords: &[Point], raw: &[(Point, Source)], d: f64) -> Vec<Vec<Point>> {
+    let pts: Vec<Point> = raw.iter().map(|(pt, _)| *pt).collect();
+    let line = LineString::new(coords.to_vec());
+    let mut parts: Vec<Vec<Point>> = vec![];
+    let mut cur: Vec<Point> = vec![];
+    for (k, split) in node_self(&pts).iter().enumerate() {
+        for w in split.windows(2) {
+            let mid = Point::new((w[0].x + w[1].x) / 2.0, (w[0].y + w[1].y) / 2.0);
+            let reach = match raw[k].1 {
+                Source::Segment(_) => d,
+                Source::Vertex(i) => mid.point_distance(&coords[i]),
+                Source::Inner => continue,
+            };
+            if closer_than(&line, &mid, reach * (1.0 - 1e-9)) {
+                continue;
+            }
+            if cur.last().is_some_and(|pt| pt.equals(&w[0])) {
+                cur.push(w[1]);
+            } else {
+                if cur.len() > 1 {
+                    parts.push(std::mem::take(&mut cur));
+                }
+                cur = vec![w[0], w[1]];
+            }
+        }
+    }
+    if cur.len() > 1 {
+        parts.push(cur);
+    }
+    parts
+}
+
+//some segment of the line is within dist of pt, only those in the box around pt are measured
+fn closer_than(line: &LineString, pt: &Point, dist: f64) -> bool {
+    let coords = &line.coordinates;
+    let mbr = MBR::new(pt.x - dist, pt.y - dist, pt.x + dist, pt.y + dist);
+    line.segments_in(&mbr).into_iter()
+        .any(|i| segment::distance_to_point(&coords[i], &coords[i + 1], pt) < dist)
+}
+
+//segment quads, joins and caps whose union is the buffer of a line
+fn line_pieces(coords: &[Point], d: f64, params: &BufferParams) -> Vec<Polygon> {
+    let n = coords.len();
+    let closed = n > 3 && coords[0].equals(&coords[n - 1]);
+    let mut pieces = vec![];
+    for w in coords.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        let nm = normal(&a, &b, d);
+        pieces.push(vec![offset(&a, &nm, 1.0), offset(&b, &nm, 1.0), offset(&b, &nm, -1.0), offset(&a, &nm, -1.0)]);
+    }
+    for i in 1..n - 1 {
+        pieces.extend(join(&coords[i - 1], &coords[i], &coords[i + 1], d, params));
+    }
+    if closed {
+        pieces.extend(join(&coords[n - 2], &coords[0], &coords[1], d, params));
+    } else {
+        pieces.extend(cap(&coords[1], &coords[0], d, params));
+        pieces.extend(cap(&coords[n - 2], &coords[n - 1], d, params));
+    }
+    pieces.into_iter()
+        .filter(|ring| signed_area(&closed_ring(ring)) != 0.0)
+        .map(|ring| Polygon::new(vec![ring]))
+        .collect()
+}
+
+fn closed_ring(coords: &[Point]) -> Vec<Point> {
+    let mut ring = coords.to_vec();
+    ring.push(coords[0]);
+    ring
+}
+
+//left normal of a, b with length d
+fn normal(a: &Point, b: &Point, d: f64) -> Point {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len = dx.hypot(dy);
+    Point::new(-dy / len * d, dx / len * d)
+}
+
+fn offset(pt: &Point, nm: &Point, side: f64) -> Point {
+    Point::new(pt.x + side * nm.x, pt.y + side * nm.y)
+}
+
+//points strictly between from and to on the arc around c, turning by sweep radians
+fn arc(c: &Point, from: &Point, sweep: f64, d: f64, quadrant_segments: usize) -> Vec<Point> {
+    let start = (from.y - c.y).atan2(from.x - c.x);
+    let step = PI / 2.0 / (quadrant_segments.max(1) as f64);
+    let n = (sweep.abs() / step).ceil() as usize;
+    (1..n).map(|k| {
+        let a = start + sweep * (k as f64) / (n as f64);
+        Point::new(c.x + d * a.cos(), c.y + d * a.sin())
+    }).collect()
+}
+
+//piece filling the outer side of the turn p, v, q
+fn join(p: &Point, v: &Point, q: &Point, d: f64, params: &BufferParams) -> Option<Vec<Point>> {
+    let (n1, n2) = (normal(p, v, d), normal(v, q, d));
+    let (u1, u2) = (Point::new(v.x - p.x, v.y - p.y), Point::new(q.x - v.x, q.y - v.y));
+    let cross = u1.x * u2.y - u1.y * u2.x;
+    let dot = u1.x * u2.x + u1.y * u2.y;
+    if cross == 0.0 && dot > 0.0 {
+        return None;
+    }
+    let angle = cross.abs().atan2(dot);
+    //a left turn opens on the right, a right turn or reversal on the left
+    let (side, sweep) = if cross > 0.0 { (-1.0, angle) } else { (1.0, -angle) };
+    let (o1, o2) = (offset(v, &n1, side), offset(v, &n2, side));
+    let mut coords = vec![*v, o1];
+    coords.extend(corner(v, &o1, &o2, sweep, d, params.join, params.quadrant_segments));
+    coords.push(o2);
+    Some(coords)
+}
+
+//points strictly between the offsets o1 and o2 of the outer side of a turn at v
+fn corner(v: &Point, o1: &Point, o2: &Point, sweep: f64, d: f64, join: JoinStyle, quadrant_segments: usize) -> Vec<Point> {
+    match join {
+        JoinStyle::Round => arc(v, o1, sweep, d, quadrant_segments),
+        JoinStyle::Mitre => {
+            let ratio = 1.0 / (sweep.abs() / 2.0).cos();
+            if !ratio.is_finite() || ratio > MITRE_LIMIT {
+                return vec![];
+            }
+            //crossing of the offset lines : v + (a + b) d^2 / (d^2 + a.b) with a, b the offsets at v
+            let (a, b) = (Point::new(o1.x - v.x, o1.y - v.y), Point::new(o2.x - v.x, o2.y - v.y));
+            let k = d * d / (d * d + a.x * b.x + a.y * b.y);
+            vec![Point::new(v.x + (a.x + b.x) * k, v.y + (a.y + b.y) * k)]
+        }
+        JoinStyle::Bevel => vec![],
+    }
+}
+
+//piece beyond the end e of the last segment p, e
+fn cap(p: &Point, e: &Point, d: f64, params: &BufferParams) -> Option<Vec<Point>> {
+    let nm = normal(p, e, d);
+    let (l, r) = (offset(e, &nm, 1.0), offset(e, &nm, -1.0));
+    match params.cap {
+        CapStyle::Flat => None,
+        CapStyle::Square => {
+            //forward is the left normal turned clockwise
+            let (fx, fy) = (nm.y, -nm.x);
+            Some(vec![l, Point::new(l.x + fx, l.y + fy), Point::new(r.x + fx, r.y + fy), r])
+        }
+        CapStyle::Round => {
+            let mut coords = vec![l];
+            coords.extend(arc(e, &l, -PI, d, params.quadrant_segments));
+            coords.push(r);
+            Some(coords)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,151 @@
+use super::*;
+use crate::{LineString, Segment, Geometry, Location};
+
+fn params(join: JoinStyle, cap: CapStyle) -> BufferParams {
+    BufferParams { quadrant_segments: 8, join, cap }
+}
+
+//area of a regular polygon with n vertices inscribed in a circle of radius r
+fn ngon(n: usize, r: f64) -> f64 {
+    0.5 * (n as f64) * (2.0 * PI / (n as f64)).sin() * r * r
+}
+
+#[test]
+fn test_buffer_point() {
+    let pt = Point::new(1., 2.);
+    let plys = pt.buffer(2., BufferParams::default());
+    assert_eq!(plys.polygons().len(), 1);
+    assert_eq!(plys.polygons()[0].shell().coordinates().len(), 33);
+    assert!((plys.area() - ngon(32, 2.)).abs() < 1e-9);
+    assert_eq!(plys.polygons()[0].locate_point(&pt), Location::Interior);
+
+    let plys = pt.buffer(2., params(JoinStyle::Round, CapStyle::Square));
+    assert!((plys.area() - 16.).abs() < 1e-9);
+    assert!(pt.buffer(2., params(JoinStyle::Round, CapStyle::Flat)).polygons().is_empty());
+    assert!(pt.buffer(-1., BufferParams::default()).polygons().is_empty());
+}
+
+#[test]
+fn test_buffer_segment() {
+    let seg = Segment::new(Point::new(0., 0.), Point::new(10., 0.));
+    assert!((seg.buffer(1., params(JoinStyle::Round, CapStyle::Flat)).area() - 20.).abs() < 1e-9);
+    assert!((seg.buffer(1., params(JoinStyle::Round, CapStyle::Square)).area() - 24.).abs() < 1e-9);
+    let plys = seg.buffer(1., BufferParams::default());
+    assert_eq!(plys.polygons().len(), 1);
+    assert!((plys.area() - (20. + ngon(32, 1.))).abs() < 1e-9);
+}
+
+#[test]
+fn test_buffer_line_joins() {
+    //right angle turn to the left, outer corner on the right
+    let ln = LineString::from_wkt("LINESTRING(0 0,10 0,10 10)");
+    let flat = 40. - 1.;
+    let plys = ln.buffer(1., params(JoinStyle::Bevel, CapStyle::Flat));
+    assert_eq!(plys.polygons().len(), 1);
+    assert!((plys.area() - (flat + 0.5)).abs() < 1e-9);
+    let plys = ln.buffer(1., params(JoinStyle::Mitre, CapStyle::Flat));
+    assert!((plys.area() - (flat + 1.)).abs() < 1e-9);
+    let plys = ln.buffer(1., params(JoinStyle::Round, CapStyle::Flat));
+    assert!((plys.area() - (flat + ngon(32, 1.) / 4.)).abs() < 1e-9);
+    assert_eq!(plys.polygons()[0].locate_point(&Point::new(10.7, -0.7)), Location::Interior);
+    assert_eq!(plys.polygons()[0].locate_point(&Point::new(10.8, -0.8)), Location::Exterior);
+
+    //a spike within the mitre limit and a closed line with a hole
+    let ln = LineString::from_wkt("LINESTRING(0 0,10 0,0 0.5)");
+    assert_eq!(ln.buffer(0.1, params(JoinStyle::Mitre, CapStyle::Flat)).polygons().len(), 1);
+    let ring = LineString::from_wkt("LINESTRING(0 0,10 0,10 10,0 10,0 0)");
+    let plys = ring.buffer(1., params(JoinStyle::Mitre, CapStyle::Round));
+    assert_eq!(plys.polygons().len(), 1);
+    assert_eq!(plys.polygons()[0].holes().len(), 1);
+    assert!((plys.area() - (144. - 64.)).abs() < 1e-9);
+}
+
+#[test]
+fn test_buffer_polygon() {
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0))");
+    let plys = ply.buffer(1., params(JoinStyle::Mitre, CapStyle::Round));
+    assert_eq!(plys.polygons().len(), 1);
+    assert_eq!(plys.polygons()[0].holes().len(), 0);
+    assert!((plys.area() - 144.).abs() < 1e-9);
+    let plys = ply.buffer(1., params(JoinStyle::Bevel, CapStyle::Round));
+    assert!((plys.area() - 142.).abs() < 1e-9);
+    let plys = ply.buffer(1., BufferParams::default());
+    assert!((plys.area() - (140. + ngon(32, 1.))).abs() < 1e-9);
+
+    //negative distance erodes, holes grow
+    let plys = ply.buffer(-1., BufferParams::default());
+    assert_eq!(plys.polygons().len(), 1);
+    assert!((plys.area() - 64.).abs() < 1e-9);
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(4 4,6 4,6 6,4 6,4 4))");
+    let plys = ply.buffer(-1., params(JoinStyle::Mitre, CapStyle::Round));
+    assert_eq!(plys.polygons().len(), 1);
+    assert!((plys.area() - (64. - 16.)).abs() < 1e-9);
+    assert!(ply.buffer(-6., BufferParams::default()).polygons().is_empty());
+
+    //a narrow waist splits into two parts
+    let ply = Polygon::from_wkt("POLYGON((0 0,4 0,4 1.5,6 1.5,6 0,10 0,10 4,6 4,6 2.5,4 2.5,4 4,0 4,0 0))");
+    assert_eq!(ply.buffer(-1., params(JoinStyle::Mitre, CapStyle::Round)).polygons().len(), 2);
+}
+
+#[test]
+fn test_offset_curve() {
+    let ln = LineString::from_wkt("LINESTRING(0 0,10 0,10 10)");
+    //left is inside the turn, the corner is cut at the crossing of the offsets
+    let left = ln.offset_curve(1., JoinStyle::Round);
+    assert_eq!(left.len(), 1);
+    assert_eq!(left[0].wkt(), "LINESTRING(0 1,9 1,9 10)");
+    assert!(Point::new(0., 1.).side_of(&Point::new(0., 0.), &Point::new(10., 0.)).is_left());
+
+    let right = ln.offset_curve(-1., JoinStyle::Mitre);
+    assert_eq!(right[0].wkt(), "LINESTRING(0 -1,10 -1,11 -1,11 0,11 10)");
+    let right = ln.offset_curve(-1., JoinStyle::Bevel);
+    assert_eq!(right[0].wkt(), "LINESTRING(0 -1,10 -1,11 0,11 10)");
+    let right = ln.offset_curve(-1., JoinStyle::Round);
+    assert_eq!(right[0].coordinates.len(), 4 + 7);
+    for pt in right[0].coordinates.iter() {
+        assert!((ln.distance(pt) - 1.).abs() < 1e-9);
+    }
+    assert_eq!(ln.offset_curve(0., JoinStyle::Round)[0].wkt(), ln.wkt());
+}
+
+#[test]
+fn test_offset_curve_sharp_turns() {
+    //u-turn, the inner side has no loop and vanishes once narrower than twice the distance
+    let ln = LineString::from_wkt("LINESTRING(0 0,10 0,10 3,0 3)");
+    assert!(ln.offset_curve(2., JoinStyle::Round).is_empty());
+    let inner = ln.offset_curve(1., JoinStyle::Mitre);
+    assert_eq!(inner.len(), 1);
+    assert_eq!(inner[0].wkt(), "LINESTRING(0 1,9 1,9 2,0 2)");
+    let outer = ln.offset_curve(-1., JoinStyle::Bevel);
+    assert_eq!(outer.len(), 1);
+    assert_eq!(outer[0].wkt(), "LINESTRING(0 -1,10 -1,11 0,11 3,10 4,0 4)");
+
+    //spike folding back on itself
+    let ln = LineString::from_wkt("LINESTRING(0 0,10 0,0 0.5)");
+    for &d in [0.2, -0.2].iter() {
+        for part in ln.offset_curve(d, JoinStyle::Round).iter() {
+            for pt in part.coordinates.iter() {
+                assert!(ln.distance(pt) >= 0.2 - 1e-9);
+            }
+        }
+    }
+
+    //closed line gives a closed curve
+    let ring = LineString::from_wkt("LINESTRING(0 0,10 0,10 10,0 10,0 0)");
+    let out = ring.offset_curve(-1., JoinStyle::Mitre);
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].wkt(), "LINESTRING(0 -1,10 -1,11 -1,11 0,11 10,11 11,10 11,0 11,-1 11,-1 10,-1 0,-1 -1,0 -1)");
+    let inn = ring.offset_curve(1., JoinStyle::Mitre);
+    assert_eq!(inn.len(), 1);
+    assert_eq!(inn[0].wkt(), "LINESTRING(1 1,9 1,9 9,1 9,1 1)");
+}
+
+#[test]
+fn test_buffer_long_line() {
+    //zigzag whose pieces all overlap their neighbours
+    let coords: Vec<Point> = (0..300).map(|i| Point::new(i as f64, (i % 2) as f64)).collect();
+    let plys = LineString::new(coords).buffer(1., params(JoinStyle::Round, CapStyle::Flat));
+    assert_eq!(plys.polygons().len(), 1);
+    assert_eq!(plys.polygons()[0].holes().len(), 0);
+    assert!(plys.area() > 299. * 2.);
+}
@@ -0,0 +1,122 @@
+use crate::{Point, Topology};
+use crate::relate::signed_area;
+
+///Centroid of the highest dimension components : area weighted for polygons,
+///length weighted for lines, mean of points. Collapsed polygons and lines fall
+///back to the next lower dimension.
+pub fn centroid(topo: &Topology) -> Point {
+    let rings = topo.polygons.iter().flat_map(|rings| {
+        rings.iter().enumerate().map(|(i, r)| (i == 0, r.as_slice()))
+    });
+    if let Some(pt) = area_centroid(rings) {
+        return pt;
+    }
+    let lines = topo.lines.iter().chain(topo.polygons.iter().flatten());
+    if let Some(pt) = length_centroid(lines.map(|ln| ln.as_slice())) {
+        return pt;
+    }
+    let pts: Vec<&Point> = topo.points.iter()
+        .chain(topo.lines.iter().flatten())
+        .chain(topo.polygons.iter().flatten().flatten())
+        .collect();
+    let n = pts.len() as f64;
+    let (x, y) = pts.iter().fold((0.0, 0.0), |(x, y), pt| (x + pt.x, y + pt.y));
+    Point::new(x / n, y / n)
+}
+
+///Point in the interior of the highest dimension components, for polygons the middle
+///of the widest interior span on a horizontal line through the middle of its bounds
+pub fn interior_point(topo: &Topology) -> Point {
+    let c = centroid(topo);
+    let span = topo.polygons.iter()
+        .filter_map(|rings| widest_span(rings))
+        .max_by(|a, b| (a.1 - a.0).partial_cmp(&(b.1 - b.0)).unwrap());
+    if let Some((x0, x1, y)) = span {
+        return Point::new((x0 + x1) / 2.0, y);
+    }
+    let lines: Vec<&Vec<Point>> = topo.lines.iter().chain(topo.polygons.iter().flatten()).collect();
+    //inner vertices of lines, else their endpoints
+    let inner = lines.iter().flat_map(|ln| ln[1..ln.len() - 1].iter());
+    let ends = lines.iter().flat_map(|ln| vec![&ln[0], &ln[ln.len() - 1]]);
+    closest(inner, &c)
+        .or_else(|| closest(ends, &c))
+        .or_else(|| closest(topo.points.iter(), &c))
+        .unwrap_or(c)
+}
+
+//sum over rings of area and first moments, shells add and holes subtract
+fn area_centroid<'a, I>(rings: I) -> Option<Point> where I: Iterator<Item=(bool, &'a [Point])> {
+    let (mut area, mut cx, mut cy) = (0.0, 0.0, 0.0);
+    let mut origin = None;
+    for (is_shell, coords) in rings {
+        //moments taken about the first vertex seen
+        let o = *origin.get_or_insert(coords[0]);
+        let (mut a, mut mx, mut my) = (0.0, 0.0, 0.0);
+        for w in coords.windows(2) {
+            let (x0, y0, x1, y1) = (w[0].x - o.x, w[0].y - o.y, w[1].x - o.x, w[1].y - o.y);
+            let cross = x0 * y1 - x1 * y0;
+            a += cross;
+            mx += (x0 + x1) * cross;
+            my += (y0 + y1) * cross;
+        }
+        //orientation free : shells count positive, holes negative
+        let sign = if (a > 0.0) == is_shell { 1.0 } else { -1.0 };
+        area += sign * a / 2.0;
+        cx += sign * mx / 6.0;
+        cy += sign * my / 6.0;
+    }
+    match origin {
+        Some(o) if area != 0.0 => Some(Point::new(o.x + cx / area, o.y + cy / area)),
+        _ => None,
+    }
+}
+
+//segment midpoints weighted by segment length
+fn length_centroid<'a, I>(lines: I) -> Option<Point> where I: Iterator<Item=&'a [Point]> {
+    let (mut len, mut cx, mut cy) = (0.0, 0.0, 0.0);
+    for coords in lines {
+        for w in coords.windows(2) {
+            let d = w[0].point_distance(&w[1]);
+            len += d;
+            cx += d * (w[0].x + w[1].x) / 2.0;
+            cy += d * (w[0].y + w[1].y) / 2.0;
+        }
+    }
+    if len > 0.0 { Some(Point::new(cx / len, cy / len)) } else { None }
+}
+
+//widest interior span (x0, x1, y) on a scan line that avoids all vertices
+fn widest_span(rings: &[Vec<Point>]) -> Option<(f64, f64, f64)> {
+    let shell = rings.first()?;
+    let (lo, hi) = shell.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), pt| {
+        (lo.min(pt.y), hi.max(pt.y))
+    });
+    let mid = (lo + hi) / 2.0;
+    //halfway between the closest vertex ordinates below and above the middle
+    let (mut below, mut above) = (lo, hi);
+    for pt in rings.iter().flatten() {
+        if pt.y <= mid && pt.y > below {
+            below = pt.y;
+        } else if pt.y > mid && pt.y < above {
+            above = pt.y;
+        }
+    }
+    let y = (below + above) / 2.0;
+    if !(y > lo && y < hi) || signed_area(shell) == 0.0 {
+        return None;
+    }
+    let mut xs: Vec<f64> = rings.iter()
+        .flat_map(|r| r.windows(2))
+        .filter(|w| (w[0].y < y) != (w[1].y < y))
+        .map(|w| w[0].x + (y - w[0].y) * (w[1].x - w[0].x) / (w[1].y - w[0].y))
+        .collect();
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    xs.chunks(2)
+        .filter(|c| c.len() == 2)
+        .map(|c| (c[0], c[1], y))
+        .max_by(|a, b| (a.1 - a.0).partial_cmp(&(b.1 - b.0)).unwrap())
+}
+
+fn closest<'a, I>(pts: I, c: &Point) -> Option<Point> where I: Iterator<Item=&'a Point> {
+    pts.min_by(|a, b| a.point_distance(c).partial_cmp(&b.point_distance(c)).unwrap()).cloned()
+}
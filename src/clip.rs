@@ -0,0 +1,165 @@
+use crate::{Point, LineString, Polygon};
+use crate::relate::signed_area;
+use bbox_2d::MBR;
+
+///Pieces of line inside box, Liang-Barsky clipping of each segment
+pub fn clip_line(ln: &LineString, mbr: &MBR) -> Vec<LineString> {
+    let (ll, ur) = (mbr.ll(), mbr.ur());
+    let mut parts = vec![];
+    let mut cur: Vec<Point> = vec![];
+    for w in ln.coordinates.windows(2) {
+        match clip_segment(&w[0], &w[1], &ll, &ur) {
+            Some((p, q)) => {
+                let connected = cur.last().is_some_and(|last| last.equals(&p));
+                if p.equals(&q) && connected {
+                    continue;
+                }
+                if !connected {
+                    flush(&mut parts, &mut cur);
+                    if p.equals(&q) {
+                        //touches the box at a point
+                        continue;
+                    }
+                    cur.push(p);
+                }
+                cur.push(q);
+            }
+            None => flush(&mut parts, &mut cur),
+        }
+    }
+    flush(&mut parts, &mut cur);
+    parts
+}
+
+///Polygon inside box, Sutherland-Hodgman clipping of the shell.
+///Concave parts cut by the box stay connected by edges along the box boundary.
+///Holes inside the box are kept, holes reaching the box boundary are cut out of
+///the shell by overlay and may split it into several parts.
+pub fn clip_polygon(ply: &Polygon, mbr: &MBR) -> Vec<Polygon> {
+    let bounds = ply.bounds();
+    if mbr.disjoint(&bounds) {
+        return vec![];
+    }
+    let (ll, ur) = (bounds.ll(), bounds.ur());
+    if mbr.contains_xy(ll[0], ll[1]) && mbr.contains_xy(ur[0], ur[1]) {
+        return vec![ply.clone()];
+    }
+    let (ll, ur) = (mbr.ll(), mbr.ur());
+    let shell = clip_ring(ply.shell().coordinates(), &ll, &ur);
+    if shell.len() < 4 || signed_area(&shell) == 0.0 {
+        return vec![];
+    }
+    let mut rings = vec![shell];
+    let mut cuts = vec![];
+    for hole in ply.holes().iter() {
+        let hb = hole.bbox();
+        let (hl, hu) = (hb.ll(), hb.ur());
+        if hl[0] > ll[0] && hl[1] > ll[1] && hu[0] < ur[0] && hu[1] < ur[1] {
+            rings.push(hole.coordinates().clone());
+        } else if mbr.intersects(hb) {
+            cuts.push(Polygon::new(vec![hole.coordinates().clone()]));
+        }
+    }
+    let mut parts = vec![Polygon::new(rings)];
+    for cut in cuts.iter() {
+        parts = parts.iter().flat_map(|p| p.difference(cut)).collect();
+    }
+    parts
+}
+
+fn flush(parts: &mut Vec<LineString>, cur: &mut Vec<Point>) {
+    if cur.len() > 1 {
+        parts.push(LineString::new(std::mem::take(cur)));
+    } else {
+        cur.clear();
+    }
+}
+
+//Liang-Barsky : part of segment a, b inside box ll, ur
+fn clip_segment(a: &Point, b: &Point, ll: &[f64; 2], ur: &[f64; 2]) -> Option<(Point, Point)> {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let p = [-dx, dx, -dy, dy];
+    let q = [a.x - ll[0], ur[0] - a.x, a.y - ll[1], ur[1] - a.y];
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    for k in 0..4 {
+        if p[k] == 0.0 {
+            if q[k] < 0.0 {
+                return None;
+            }
+        } else {
+            let r = q[k] / p[k];
+            if p[k] < 0.0 {
+                if r > t1 {
+                    return None;
+                }
+                t0 = t0.max(r);
+            } else {
+                if r < t0 {
+                    return None;
+                }
+                t1 = t1.min(r);
+            }
+        }
+    }
+    let at = |t: f64| if t == 0.0 {
+        *a
+    } else if t == 1.0 {
+        *b
+    } else {
+        Point::new(a.x + t * dx, a.y + t * dy)
+    };
+    Some((at(t0), at(t1)))
+}
+
+//Sutherland-Hodgman : ring against each box edge in turn
+fn clip_ring(coords: &[Point], ll: &[f64; 2], ur: &[f64; 2]) -> Vec<Point> {
+    //open ring
+    let mut pts: Vec<Point> = coords[..coords.len() - 1].to_vec();
+    //(axis, bound, keep greater)
+    let edges = [(0, ll[0], true), (0, ur[0], false), (1, ll[1], true), (1, ur[1], false)];
+    for &(axis, bound, greater) in edges.iter() {
+        if pts.is_empty() {
+            break;
+        }
+        let inside = |pt: &Point| {
+            let v = if axis == 0 { pt.x } else { pt.y };
+            if greater { v >= bound } else { v <= bound }
+        };
+        let mut out = Vec::with_capacity(pts.len() + 4);
+        let mut prev = pts[pts.len() - 1];
+        for cur in pts.iter() {
+            match (inside(&prev), inside(cur)) {
+                (true, true) => out.push(*cur),
+                (true, false) => out.push(cross(&prev, cur, axis, bound)),
+                (false, true) => {
+                    out.push(cross(&prev, cur, axis, bound));
+                    out.push(*cur);
+                }
+                (false, false) => {}
+            }
+            prev = *cur;
+        }
+        pts = out;
+    }
+    pts.dedup_by(|p, q| p.equals(q));
+    if let (Some(first), Some(last)) = (pts.first().cloned(), pts.last()) {
+        if !first.equals(last) {
+            pts.push(first);
+        }
+    }
+    pts
+}
+
+//intersection of segment a, b with x = bound (axis 0) or y = bound (axis 1)
+fn cross(a: &Point, b: &Point, axis: usize, bound: f64) -> Point {
+    if axis == 0 {
+        let t = (bound - a.x) / (b.x - a.x);
+        Point::new(bound, a.y + t * (b.y - a.y))
+    } else {
+        let t = (bound - a.y) / (b.y - a.y);
+        Point::new(a.x + t * (b.x - a.x), bound)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+use super::*;
+use crate::Geometry;
+
+#[test]
+fn test_clip_line() {
+    let mbr = MBR::new(0., 0., 10., 10.);
+    let wkts = |lns: Vec<LineString>| lns.iter().map(|ln| ln.wkt()).collect::<Vec<_>>();
+
+    let ln = LineString::from_wkt("LINESTRING(-5 5,5 5,5 15,8 15,8 5,15 5)");
+    assert_eq!(wkts(ln.clip_to_mbr(&mbr)), vec!["LINESTRING(0 5,5 5,5 10)", "LINESTRING(8 10,8 5,10 5)"]);
+    let inside = LineString::from_wkt("LINESTRING(1 1,2 2,2 2,3 1)");
+    assert_eq!(wkts(inside.clip_to_mbr(&mbr)), vec!["LINESTRING(1 1,2 2,3 1)"]);
+    let diagonal = LineString::from_wkt("LINESTRING(-5 -5,15 15)");
+    assert_eq!(wkts(diagonal.clip_to_mbr(&mbr)), vec!["LINESTRING(0 0,10 10)"]);
+    //touches a corner, along an edge
+    let corner = LineString::from_wkt("LINESTRING(-5 5,0 10,-5 15)");
+    assert!(corner.clip_to_mbr(&mbr).is_empty());
+    let edge = LineString::from_wkt("LINESTRING(-5 0,15 0)");
+    assert_eq!(wkts(edge.clip_to_mbr(&mbr)), vec!["LINESTRING(0 0,10 0)"]);
+    let outside = LineString::from_wkt("LINESTRING(20 20,30 30)");
+    assert!(outside.clip_to_mbr(&mbr).is_empty());
+}
+
+#[test]
+fn test_clip_polygon() {
+    let mbr = MBR::new(0., 0., 10., 10.);
+    let area = |plys: &[Polygon]| plys.iter().map(|p| p.area()).sum::<f64>();
+    let ply = Polygon::from_wkt("POLYGON((-5 -5,-5 5,5 5,5 -5,-5 -5))");
+    assert_eq!(area(&ply.clip_to_mbr(&mbr)), 25.);
+
+    //the hole across the corner of the box becomes a notch in the shell
+    let holed = Polygon::from_wkt("POLYGON((-5 -5,-5 15,15 15,15 -5,-5 -5),(2 2,2 4,4 4,4 2,2 2),(8 8,8 12,12 12,12 8,8 8))");
+    let clipped = holed.clip_to_mbr(&mbr);
+    assert_eq!(clipped.len(), 1);
+    assert!(clipped[0].is_valid());
+    assert_eq!(clipped[0].holes().len(), 1);
+    assert_eq!(area(&clipped), 100. - 4. - 4.);
+    assert_eq!(clipped[0].shell().coordinates().len(), 7);
+
+    //a hole across the whole box splits the shell
+    let cut = Polygon::from_wkt("POLYGON((-5 -5,-5 15,15 15,15 -5,-5 -5),(-1 4,11 4,11 6,-1 6,-1 4))");
+    let clipped = cut.clip_to_mbr(&mbr);
+    assert_eq!(clipped.len(), 2);
+    assert!(clipped.iter().all(|p| p.is_valid() && p.holes().is_empty()));
+    assert_eq!(area(&clipped), 80.);
+
+    let within = Polygon::from_wkt("POLYGON((1 1,1 2,2 2,2 1,1 1))");
+    assert_eq!(within.clip_to_mbr(&mbr)[0].wkt(), within.wkt());
+    let far = Polygon::from_wkt("POLYGON((20 20,20 30,30 30,30 20,20 20))");
+    assert!(far.clip_to_mbr(&mbr).is_empty());
+    //shares only an edge with the box
+    let beside = Polygon::from_wkt("POLYGON((10 0,10 10,20 10,20 0,10 0))");
+    assert!(beside.clip_to_mbr(&mbr).is_empty());
+
+    let triangle = Polygon::from_wkt("POLYGON((5 -5,15 5,-5 5,5 -5))");
+    assert_eq!(area(&triangle.clip_to_mbr(&mbr)), 50.);
+}
@@ -0,0 +1,187 @@
+use crate::{Point, LineString, Geometry, GeomType, GeomError, Topology, parse_wkt, util};
+use crate::wkt::{WKT, to_geometry};
+use crate::wkb::{self, ByteOrder};
+use crate::geojson;
+use serde_json::{Value, json};
+use bbox_2d::MBR;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use std::convert::TryFrom;
+
+#[derive(Clone)]
+pub struct GeometryCollection(pub Vec<Box<dyn Geometry>>);
+
+impl GeometryCollection {
+    ///Construct from geometries
+    pub fn new(geometries: Vec<Box<dyn Geometry>>) -> GeometryCollection {
+        if geometries.is_empty() {
+            panic!("a geometry collection must have at least 1 geometry");
+        }
+        GeometryCollection(geometries)
+    }
+
+    ///Construct from wkt
+    pub fn from_wkt(s: &str) -> GeometryCollection {
+        match GeometryCollection::try_from(s) {
+            Ok(gc) => gc,
+            Err(err) => panic!("{}", err)
+        }
+    }
+
+    ///Construct from wkt, returns an error on invalid input
+    pub fn from_wkt_checked(s: &str) -> Result<GeometryCollection, GeomError> {
+        GeometryCollection::from_str(s)
+    }
+
+    ///Construct from wkb
+    pub fn from_wkb(buf: &[u8]) -> Result<GeometryCollection, GeomError> {
+        GeometryCollection::try_from(wkb::parse_wkb(buf)?)
+    }
+
+    ///Construct from GeoJSON geometry object
+    pub fn from_geojson(s: &str) -> Result<GeometryCollection, GeomError> {
+        GeometryCollection::try_from(geojson::parse_geojson(s)?)
+    }
+
+    pub fn geometries(&self) -> &[Box<dyn Geometry>] {
+        &self.0
+    }
+
+    pub fn wkt(&self) -> String {
+        format!("GEOMETRYCOLLECTION({})", self.0
+            .iter()
+            .map(|g| g.wkt_string())
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+}
+
+impl Geometry for GeometryCollection {
+    fn bbox(&self) -> MBR {
+        //empty members, nested collections included, have NaN bounds
+        let mut mbr: Option<MBR> = None;
+        for b in self.0.iter().map(|g| g.bbox()).filter(|b| !b.ll()[0].is_nan()) {
+            match mbr.as_mut() {
+                Some(m) => { m.expand_to_include(&b); }
+                None => mbr = Some(b),
+            }
+        }
+        mbr.unwrap_or_else(util::empty_mbr)
+    }
+
+    fn as_linear(&self) -> Vec<LineString> {
+        self.0.iter().flat_map(|g| g.as_linear()).collect()
+    }
+
+    fn wkt_string(&self) -> String {
+        self.wkt()
+    }
+
+    fn wkb_bytes(&self, byte_order: ByteOrder) -> Vec<u8> {
+        let mut buf = Vec::new();
+        wkb::write_header(&mut buf, byte_order, wkb::WKB_GEOMETRYCOLLECTION);
+        wkb::write_u32(&mut buf, byte_order, self.0.len() as u32);
+        for g in self.0.iter() {
+            buf.extend(g.wkb_bytes(byte_order));
+        }
+        buf
+    }
+
+    fn geojson_value(&self) -> Value {
+        json!({
+            "type": "GeometryCollection",
+            "geometries": self.0.iter().map(|g| g.geojson_value()).collect::<Vec<_>>(),
+        })
+    }
+
+    fn geom_type(&self) -> GeomType {
+        GeomType::GeometryCollection
+    }
+
+    fn intersects(&self, other: &dyn Geometry) -> bool {
+        self.0.iter().any(|g| g.intersects(other))
+    }
+
+    fn intersection(&self, other: &dyn Geometry) -> Vec<Point> {
+        let mut ptset = BTreeSet::new();
+        for g in self.0.iter() {
+            g.intersection(other).iter()
+                .for_each(|v| { ptset.insert(*v); });
+        }
+        ptset.into_iter().collect()
+    }
+
+    fn area(&self) -> f64 {
+        self.0.iter().map(|g| g.area()).sum()
+    }
+
+    fn topology(&self) -> Topology {
+        let mut topo = Topology::default();
+        for g in self.0.iter() {
+            topo.extend(g.topology());
+        }
+        topo
+    }
+
+    ///Distance to the nearest member, NaN for an empty collection
+    fn distance(&self, other: &dyn Geometry) -> f64 {
+        //f64::min skips NaN, the distance of empty members
+        self.0.iter()
+            .map(|g| g.distance(other))
+            .fold(f64::NAN, f64::min)
+    }
+}
+
+impl std::fmt::Display for GeometryCollection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.wkt())
+    }
+}
+
+impl std::fmt::Debug for GeometryCollection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "GeometryCollection({})", self.wkt())
+    }
+}
+
+impl FromStr for GeometryCollection {
+    type Err = GeomError;
+    fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
+        GeometryCollection::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl TryFrom<WKT> for GeometryCollection {
+    type Error = GeomError;
+    fn try_from(o: WKT) -> Result<Self, Self::Error> {
+        match o.geom_type {
+            GeomType::GeometryCollection => {
+                if o.geometries.is_empty() {
+                    return Err(GeomError::TooFewCoordinates { expected: 1, found: 0 });
+                }
+                let mut geometries = Vec::with_capacity(o.geometries.len());
+                for g in o.geometries.into_iter() {
+                    geometries.push(to_geometry(g)?);
+                }
+                Ok(GeometryCollection(geometries))
+            }
+            _ => Err(GeomError::WrongGeomType {
+                expected: GeomType::GeometryCollection,
+                found: o.geom_type,
+            })
+        }
+    }
+}
+
+impl TryFrom<&str> for GeometryCollection {
+    type Error = GeomError;
+    fn try_from(wkt_str: &str) -> Result<Self, Self::Error> {
+        GeometryCollection::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl From<Vec<Box<dyn Geometry>>> for GeometryCollection {
+    fn from(geometries: Vec<Box<dyn Geometry>>) -> Self {
+        GeometryCollection(geometries)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::GeomType;
+
+///Errors raised when constructing geometries from external input
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeomError {
+    ///Malformed input, position is the byte offset where parsing failed
+    Parse { message: String, position: usize },
+    ///Input parsed as a different geometry type than requested
+    WrongGeomType { expected: GeomType, found: GeomType },
+    ///Geometry type the caller cannot represent
+    Unsupported { geom: String },
+    ///Not enough coordinates to build the geometry
+    TooFewCoordinates { expected: usize, found: usize },
+    ///Ring at index `ring` where first and last coordinates differ
+    UnclosedRing { ring: usize },
+    ///Well formed json that is not a valid GeoJSON object
+    InvalidGeoJson { message: String },
+}
+
+impl std::fmt::Display for GeomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            GeomError::Parse { message, position } => {
+                write!(f, "parser error at {} : {}", position, message)
+            }
+            GeomError::WrongGeomType { expected, found } => {
+                write!(f, "invalid geometry type, expected {}, got : {}", expected, found)
+            }
+            GeomError::Unsupported { geom } => {
+                write!(f, "unsupported geometry type : {}", geom)
+            }
+            GeomError::TooFewCoordinates { expected, found } => {
+                write!(f, "too few coordinates, expected at least {}, got : {}", expected, found)
+            }
+            GeomError::UnclosedRing { ring } => {
+                write!(f, "ring {} is not closed", ring)
+            }
+            GeomError::InvalidGeoJson { message } => {
+                write!(f, "invalid geojson : {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeomError {}
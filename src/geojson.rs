@@ -0,0 +1,239 @@
+use crate::{GeomType, GeomError, Geometry, Point, LinearRing};
+use crate::wkt::{WKT, to_geometry};
+use serde_json::{Value, Map, json};
+
+///Parse GeoJSON geometry object of any geometry type
+pub fn read_geojson(s: &str) -> Result<Box<dyn Geometry>, GeomError> {
+    to_geometry(parse_geojson(s)?)
+}
+
+///Parse GeoJSON geometry object
+pub fn parse_geojson(s: &str) -> Result<WKT, GeomError> {
+    parse_geojson_value(&json_value(s)?)
+}
+
+///Converts GeoJSON geometry value to parsed geometry
+pub fn parse_geojson_value(value: &Value) -> Result<WKT, GeomError> {
+    let geom_type;
+    let mut coordinates = vec![];
+    let mut geometries = vec![];
+    let obj = value.as_object().ok_or_else(|| invalid("geometry must be an object"))?;
+    let type_str = obj.get("type")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| invalid("geometry type is missing"))?;
+
+    if type_str == "GeometryCollection" {
+        geom_type = GeomType::GeometryCollection;
+        let items = obj.get("geometries")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| invalid("geometries must be an array"))?;
+        for item in items.iter() {
+            geometries.push(parse_geojson_value(item)?);
+        }
+        return Ok(WKT { geom_type, coordinates, geometries });
+    }
+
+    let coords = obj.get("coordinates").ok_or_else(|| invalid("coordinates are missing"))?;
+    match type_str {
+        "Point" => {
+            geom_type = GeomType::Point;
+            if array(coords)?.is_empty() {
+                return Err(GeomError::TooFewCoordinates { expected: 1, found: 0 });
+            }
+            coordinates.push(vec![position(coords)?]);
+        }
+        "LineString" => {
+            geom_type = GeomType::LineString;
+            coordinates.push(positions(coords)?);
+        }
+        "Polygon" => {
+            geom_type = GeomType::Polygon;
+            coordinates = rings(coords)?;
+        }
+        "MultiPoint" => {
+            geom_type = GeomType::MultiPoint;
+            coordinates.push(positions(coords)?);
+        }
+        "MultiLineString" => {
+            geom_type = GeomType::MultiLineString;
+            coordinates = rings(coords)?;
+        }
+        "MultiPolygon" => {
+            geom_type = GeomType::MultiPolygon;
+            for ply in array(coords)?.iter() {
+                geometries.push(WKT {
+                    geom_type: GeomType::Polygon,
+                    coordinates: rings(ply)?,
+                    geometries: vec![],
+                });
+            }
+        }
+        _ => return Err(GeomError::Unsupported { geom: type_str.into() }),
+    }
+    Ok(WKT { geom_type, coordinates, geometries })
+}
+
+///GeoJSON geometry object
+pub fn geometry_value(geom_type: &str, coordinates: Value) -> Value {
+    json!({"type": geom_type, "coordinates": coordinates})
+}
+
+///GeoJSON position : [x, y]
+pub fn position_value(pt: &Point) -> Value {
+    json!([pt.x, pt.y])
+}
+
+///GeoJSON array of positions
+pub fn positions_value(coordinates: &[Point]) -> Value {
+    Value::Array(coordinates.iter().map(position_value).collect())
+}
+
+///GeoJSON array of linear rings
+pub fn rings_value(rings: &[LinearRing]) -> Value {
+    Value::Array(rings.iter().map(|r| positions_value(r.coordinates())).collect())
+}
+
+///GeoJSON Feature : geometry with a properties map
+#[derive(Clone)]
+pub struct Feature {
+    pub id: Option<Value>,
+    pub geometry: Option<Box<dyn Geometry>>,
+    pub properties: Map<String, Value>,
+}
+
+impl Feature {
+    pub fn new(geometry: Box<dyn Geometry>, properties: Map<String, Value>) -> Feature {
+        Feature { id: None, geometry: Some(geometry), properties }
+    }
+
+    ///Parse GeoJSON Feature
+    pub fn from_geojson(s: &str) -> Result<Feature, GeomError> {
+        Feature::from_value(&json_value(s)?)
+    }
+
+    pub fn from_value(value: &Value) -> Result<Feature, GeomError> {
+        let obj = object_of_type(value, "Feature")?;
+        let geometry = match obj.get("geometry") {
+            None | Some(Value::Null) => None,
+            Some(g) => Some(to_geometry(parse_geojson_value(g)?)?),
+        };
+        let properties = match obj.get("properties") {
+            None | Some(Value::Null) => Map::new(),
+            Some(Value::Object(props)) => props.clone(),
+            Some(_) => return Err(invalid("properties must be an object")),
+        };
+        let id = obj.get("id").cloned();
+        Ok(Feature { id, geometry, properties })
+    }
+
+    pub fn geojson_value(&self) -> Value {
+        let mut obj = Map::new();
+        obj.insert("type".into(), "Feature".into());
+        if let Some(id) = &self.id {
+            obj.insert("id".into(), id.clone());
+        }
+        obj.insert("geometry".into(), match &self.geometry {
+            Some(g) => g.geojson_value(),
+            None => Value::Null,
+        });
+        obj.insert("properties".into(), Value::Object(self.properties.clone()));
+        Value::Object(obj)
+    }
+
+    pub fn geojson(&self) -> String {
+        self.geojson_value().to_string()
+    }
+}
+
+///GeoJSON FeatureCollection
+#[derive(Clone)]
+pub struct FeatureCollection {
+    pub features: Vec<Feature>,
+}
+
+impl FeatureCollection {
+    pub fn new(features: Vec<Feature>) -> FeatureCollection {
+        FeatureCollection { features }
+    }
+
+    ///Parse GeoJSON FeatureCollection
+    pub fn from_geojson(s: &str) -> Result<FeatureCollection, GeomError> {
+        FeatureCollection::from_value(&json_value(s)?)
+    }
+
+    pub fn from_value(value: &Value) -> Result<FeatureCollection, GeomError> {
+        let obj = object_of_type(value, "FeatureCollection")?;
+        let items = obj.get("features")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| invalid("features must be an array"))?;
+        let mut features = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            features.push(Feature::from_value(item)?);
+        }
+        Ok(FeatureCollection { features })
+    }
+
+    pub fn geojson_value(&self) -> Value {
+        json!({
+            "type": "FeatureCollection",
+            "features": self.features.iter().map(|f| f.geojson_value()).collect::<Vec<_>>(),
+        })
+    }
+
+    pub fn geojson(&self) -> String {
+        self.geojson_value().to_string()
+    }
+}
+
+fn invalid(message: &str) -> GeomError {
+    GeomError::InvalidGeoJson { message: message.into() }
+}
+
+fn json_value(s: &str) -> Result<Value, GeomError> {
+    serde_json::from_str(s).map_err(|err| {
+        //serde_json reports 1-based line and column, convert to byte offset
+        let offset: usize = s.split('\n')
+            .take(err.line().saturating_sub(1))
+            .map(|ln| ln.len() + 1)
+            .sum();
+        GeomError::Parse {
+            message: err.to_string(),
+            position: offset + err.column().saturating_sub(1),
+        }
+    })
+}
+
+fn object_of_type<'a>(value: &'a Value, type_str: &str) -> Result<&'a Map<String, Value>, GeomError> {
+    let obj = value.as_object().ok_or_else(|| invalid("expected an object"))?;
+    match obj.get("type").and_then(|v| v.as_str()) {
+        Some(t) if t == type_str => Ok(obj),
+        _ => Err(GeomError::InvalidGeoJson { message: format!("expected type {}", type_str) }),
+    }
+}
+
+fn array(value: &Value) -> Result<&Vec<Value>, GeomError> {
+    value.as_array().ok_or_else(|| invalid("coordinates must be an array"))
+}
+
+//[x, y] or [x, y, z], elevation is dropped
+fn position(value: &Value) -> Result<Point, GeomError> {
+    let pos = array(value)?;
+    if pos.len() < 2 {
+        return Err(GeomError::TooFewCoordinates { expected: 2, found: pos.len() });
+    }
+    match (pos[0].as_f64(), pos[1].as_f64()) {
+        (Some(x), Some(y)) => Ok(Point::new(x, y)),
+        _ => Err(invalid("position must contain numbers")),
+    }
+}
+
+fn positions(value: &Value) -> Result<Vec<Point>, GeomError> {
+    array(value)?.iter().map(position).collect()
+}
+
+fn rings(value: &Value) -> Result<Vec<Vec<Point>>, GeomError> {
+    array(value)?.iter().map(positions).collect()
+}
+
+#[cfg(test)]
+mod tests;
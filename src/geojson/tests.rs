@@ -0,0 +1,114 @@
+use super::*;
+use crate::{LineString, Polygon, MultiPolygon, read_wkt};
+
+#[test]
+fn test_geojson_point() {
+    let pt = Point::new(1., 2.);
+    assert_eq!(pt.geojson_value().to_string(), r#"{"coordinates":[1.0,2.0],"type":"Point"}"#);
+    assert_eq!(Point::from_geojson(r#"{"type": "Point", "coordinates": [1, 2]}"#).unwrap(), pt);
+    //elevation is dropped
+    assert_eq!(Point::from_geojson(r#"{"type": "Point", "coordinates": [1, 2, 3]}"#).unwrap(), pt);
+    let ring = LinearRing::new(vec![Point::new(0., 0.), Point::new(0., 1.), Point::new(1., 1.)]);
+    let ln = LineString::from_geojson(&ring.geojson_value().to_string()).unwrap();
+    assert_eq!(ln.wkt(), "LINESTRING(0 0,0 1,1 1,0 0)");
+}
+
+#[test]
+fn test_geojson_round_trip() {
+    let geoms = vec![
+        "POINT(3 4)",
+        "LINESTRING(0 0,5 5,7.5 -1)",
+        "POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 8,8 8,8 2,2 2))",
+        "MULTIPOINT((30 30),(31 31))",
+        "MULTILINESTRING((0 0,5 5),(10 0,10 5))",
+        "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(2 2,2 8,8 8,8 2,2 2)),((20 0,25 0,25 5,20 5,20 0)))",
+        "GEOMETRYCOLLECTION(POINT(3 4),GEOMETRYCOLLECTION(LINESTRING(0 0,5 5)))",
+    ];
+    for wkt in geoms.iter() {
+        let g = read_wkt(wkt).unwrap();
+        let s = g.geojson_value().to_string();
+        let o = read_geojson(&s).unwrap();
+        assert_eq!(o.geom_type(), g.geom_type());
+        assert_eq!(o.wkt_string(), g.wkt_string());
+    }
+    let mply = MultiPolygon::from_geojson(r#"{
+        "type": "MultiPolygon",
+        "coordinates": [[[[0, 0], [0, 1], [1, 1], [0, 0]]], [[[5, 5], [5, 6], [6, 6], [5, 5]]]]
+    }"#).unwrap();
+    assert_eq!(mply.polygons().len(), 2);
+
+    //shells are written counter clockwise and holes clockwise whatever the input
+    let ply = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,8 2,8 8,2 8,2 2))");
+    let o = Polygon::from_geojson(&ply.geojson_value().to_string()).unwrap();
+    assert!(!ply.shell().is_ccw() && o.shell().is_ccw());
+    assert!(ply.holes()[0].is_ccw() && !o.holes()[0].is_ccw());
+    assert_eq!(o.wkt(), "POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 8,8 8,8 2,2 2))");
+    let mply = MultiPolygon(vec![ply]);
+    let o = MultiPolygon::from_geojson(&mply.geojson_value().to_string()).unwrap();
+    assert!(o.polygons()[0].shell().is_ccw() && !o.polygons()[0].holes()[0].is_ccw());
+}
+
+#[test]
+fn test_geojson_feature() {
+    let s = r#"{
+        "type": "FeatureCollection",
+        "features": [
+            {"type": "Feature", "id": 7, "geometry": {"type": "Point", "coordinates": [1, 2]},
+             "properties": {"name": "a", "rank": 1}},
+            {"type": "Feature", "geometry": null, "properties": null}
+        ]
+    }"#;
+    let fc = FeatureCollection::from_geojson(s).unwrap();
+    assert_eq!(fc.features.len(), 2);
+    let f = &fc.features[0];
+    assert_eq!(f.id, Some(json!(7)));
+    assert_eq!(f.properties["name"], json!("a"));
+    assert_eq!(f.geometry.as_ref().unwrap().wkt_string(), "POINT(1 2)");
+    assert!(fc.features[1].geometry.is_none());
+    assert!(fc.features[1].properties.is_empty());
+
+    let o = FeatureCollection::from_geojson(&fc.geojson()).unwrap();
+    assert_eq!(o.geojson_value(), fc.geojson_value());
+
+    let mut props = Map::new();
+    props.insert("area".into(), json!(100.0));
+    let ply = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0))");
+    let f = Feature::new(Box::new(ply), props);
+    assert_eq!(
+        f.geojson(),
+        r#"{"geometry":{"coordinates":[[[0.0,0.0],[10.0,0.0],[10.0,10.0],[0.0,10.0],[0.0,0.0]]],"type":"Polygon"},"properties":{"area":100.0},"type":"Feature"}"#
+    );
+}
+
+#[test]
+fn test_geojson_errors() {
+    assert_eq!(
+        parse_geojson("{\"type\": \"Point\",\n \"coordinates\": [1 2]}").unwrap_err(),
+        GeomError::Parse { message: "expected `,` or `]` at line 2 column 20".into(), position: 37 },
+    );
+    assert_eq!(
+        parse_geojson(r#"{"coordinates": [1, 2]}"#).unwrap_err(),
+        GeomError::InvalidGeoJson { message: "geometry type is missing".into() },
+    );
+    assert_eq!(
+        parse_geojson(r#"{"type": "Circle", "coordinates": [1, 2]}"#).unwrap_err(),
+        GeomError::Unsupported { geom: "Circle".into() },
+    );
+    assert_eq!(
+        parse_geojson(r#"{"type": "Point", "coordinates": [1]}"#).unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 2, found: 1 },
+    );
+    assert_eq!(
+        Point::from_geojson(r#"{"type": "Point", "coordinates": []}"#).unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 1, found: 0 },
+    );
+    assert_eq!(
+        Polygon::from_geojson(r#"{"type": "Polygon", "coordinates": [[[0, 0], [0, 1], [1, 1], [1, 0]]]}"#).unwrap_err(),
+        GeomError::UnclosedRing { ring: 0 },
+    );
+    assert_eq!(
+        Point::from_geojson(r#"{"type": "LineString", "coordinates": [[0, 0], [1, 1]]}"#).unwrap_err(),
+        GeomError::WrongGeomType { expected: GeomType::Point, found: GeomType::LineString },
+    );
+    assert!(Feature::from_geojson(r#"{"type": "Point", "coordinates": [1, 2]}"#).is_err());
+}
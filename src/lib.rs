@@ -1,15 +1,32 @@
 pub mod wkt;
+pub mod wkb;
 pub mod point;
 pub mod pointz;
 pub mod line;
 pub mod ring;
 pub mod polygon;
+pub mod multi_point;
+pub mod multi_line;
+pub mod multi_polygon;
+pub mod collection;
 pub mod mono;
 pub mod util;
 pub mod chull;
 pub mod segment;
 pub mod inter;
 pub mod distance;
+pub mod relate;
+pub mod overlay;
+pub mod clip;
+pub mod simplify;
+pub mod buffer;
+pub mod centroid;
+pub mod valid;
+pub mod noding;
+pub mod polygonize;
+pub mod merge;
+pub mod geojson;
+pub mod error;
 
 pub use coordinate::Coordinate;
 pub use crate::point::{
@@ -22,17 +39,36 @@ pub use crate::line::LineString;
 pub use crate::segment::Segment;
 pub use crate::ring::LinearRing;
 pub use crate::polygon::Polygon;
-pub use crate::wkt::parse_wkt;
+pub use crate::multi_point::MultiPoint;
+pub use crate::multi_line::MultiLineString;
+pub use crate::multi_polygon::MultiPolygon;
+pub use crate::collection::GeometryCollection;
+pub use crate::wkt::{parse_wkt, read_wkt};
+pub use crate::wkb::{ByteOrder, read_wkb};
+pub use crate::geojson::{read_geojson, Feature, FeatureCollection};
+pub use crate::error::GeomError;
+pub use crate::relate::{IntersectionMatrix, Location, Topology};
+pub use crate::overlay::OverlayOp;
 pub use crate::chull::convex_hull;
+pub use crate::simplify::simplify_topology;
+pub use crate::buffer::{BufferParams, JoinStyle, CapStyle};
+pub use crate::valid::{ValidityError, ValidityErrorKind};
+pub use crate::noding::{node_lines, node_lines_with_sources, SegmentIntersection};
+pub use crate::polygonize::{polygonize, PolygonizeResult};
+pub use crate::merge::{line_merge, line_merge_directed};
 use crate::mono::MonoMBR;
 use bbox_2d::MBR;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum GeomType {
     Point,
     Segment,
     LineString,
     Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
     Unknown,
 }
 
@@ -61,14 +97,48 @@ impl GeomType {
             _ => false
         }
     }
+    pub fn is_multi_point(&self) -> bool {
+        match self {
+            GeomType::MultiPoint => true,
+            _ => false
+        }
+    }
+    pub fn is_multi_line_string(&self) -> bool {
+        match self {
+            GeomType::MultiLineString => true,
+            _ => false
+        }
+    }
+    pub fn is_multi_polygon(&self) -> bool {
+        match self {
+            GeomType::MultiPolygon => true,
+            _ => false
+        }
+    }
+    pub fn is_geometry_collection(&self) -> bool {
+        match self {
+            GeomType::GeometryCollection => true,
+            _ => false
+        }
+    }
+    ///Multi-part geometry : multi point, line string, polygon or collection
+    pub fn is_multi(&self) -> bool {
+        self.is_multi_point() || self.is_multi_line_string() ||
+            self.is_multi_polygon() || self.is_geometry_collection()
+    }
 }
 
 impl std::fmt::Display for GeomType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         let type_str: String = match self {
             GeomType::Point => "Point".into(),
+            GeomType::Segment => "Segment".into(),
             GeomType::LineString => "LineString".into(),
             GeomType::Polygon => "Polygon".into(),
+            GeomType::MultiPoint => "MultiPoint".into(),
+            GeomType::MultiLineString => "MultiLineString".into(),
+            GeomType::MultiPolygon => "MultiPolygon".into(),
+            GeomType::GeometryCollection => "GeometryCollection".into(),
             _ => "Unknown".into()
         };
         write!(f, "{}", type_str)
@@ -91,16 +161,121 @@ impl Clone for Box<dyn Geometry> {
     }
 }
 
+///An empty geometry has NaN bounds and a NaN distance to any geometry;
+///empty members of a collection are skipped.
 pub trait Geometry: GeometryClone {
     fn bbox(&self) -> MBR;
     fn as_linear(&self) -> Vec<LineString>;
     fn wkt_string(&self) -> String;
+    ///WKB of the geometry built from its topology
+    fn wkb_bytes(&self, byte_order: ByteOrder) -> Vec<u8> {
+        self.topology().into_geometry().wkb_bytes(byte_order)
+    }
+    ///GeoJSON of the geometry built from its topology
+    fn geojson_value(&self) -> serde_json::Value {
+        self.topology().into_geometry().geojson_value()
+    }
     fn geom_type(&self) -> GeomType;
     fn intersects(&self, other: &dyn Geometry) -> bool;
     fn intersection(&self, other: &dyn Geometry) -> Vec<Point>;
     fn linear_rings(&self) -> &Vec<LinearRing> { unimplemented!(); }
     fn area(&self) -> f64 { 0f64 }
     fn distance(&self, other: &dyn Geometry) -> f64;
+
+    ///Points, lines and polygons of the geometry
+    fn topology(&self) -> Topology {
+        Topology::from_linear(self.geom_type(), self.as_linear())
+    }
+
+    ///DE-9IM intersection matrix of self and other
+    fn relate(&self, other: &dyn Geometry) -> IntersectionMatrix {
+        relate::relate(&self.topology(), &other.topology())
+    }
+
+    ///Relate matches pattern e.g. `T*F**FFF*`, an error for an invalid pattern
+    fn relate_pattern(&self, other: &dyn Geometry, pattern: &str) -> Result<bool, GeomError> {
+        self.relate(other).try_matches(pattern)
+    }
+
+    ///Topological dimension : 0 points, 1 lines, 2 polygons
+    fn dimension(&self) -> u8 {
+        self.topology().dimension()
+    }
+
+    ///Centre of mass of the highest dimension components
+    fn centroid(&self) -> Point {
+        centroid::centroid(&self.topology())
+    }
+
+    ///Point guaranteed to lie in the interior of the geometry, e.g. for placing labels
+    fn interior_point(&self) -> Point {
+        centroid::interior_point(&self.topology())
+    }
+
+    ///No point in common
+    fn disjoint(&self, other: &dyn Geometry) -> bool {
+        self.relate(other).matches("FF*FF****")
+    }
+
+    ///Other lies in self and their interiors meet
+    fn contains(&self, other: &dyn Geometry) -> bool {
+        self.relate(other).matches("T*****FF*")
+    }
+
+    ///Self lies in other and their interiors meet
+    fn within(&self, other: &dyn Geometry) -> bool {
+        self.relate(other).matches("T*F**F***")
+    }
+
+    ///No point of other lies in the exterior of self
+    fn covers(&self, other: &dyn Geometry) -> bool {
+        let im = self.relate(other);
+        ["T*****FF*", "*T****FF*", "***T**FF*", "****T*FF*"].iter().any(|p| im.matches(p))
+    }
+
+    ///No point of self lies in the exterior of other
+    fn covered_by(&self, other: &dyn Geometry) -> bool {
+        let im = self.relate(other);
+        ["T*F**F***", "*TF**F***", "**FT*F***", "**F*TF***"].iter().any(|p| im.matches(p))
+    }
+
+    ///Common points lie only on the boundaries
+    fn touches(&self, other: &dyn Geometry) -> bool {
+        let im = self.relate(other);
+        ["FT*******", "F**T*****", "F***T****"].iter().any(|p| im.matches(p))
+    }
+
+    ///Interiors meet in a lower dimension than the geometries, and each
+    ///has interior points outside the other
+    fn crosses(&self, other: &dyn Geometry) -> bool {
+        let im = self.relate(other);
+        let (da, db) = (self.dimension(), other.dimension());
+        if da == 1 && db == 1 {
+            im.matches("0********")
+        } else if da < db {
+            im.matches("T*T******")
+        } else if da > db {
+            im.matches("T*****T**")
+        } else {
+            false
+        }
+    }
+
+    ///Same dimension, interiors meet in that dimension and neither covers the other
+    fn overlaps(&self, other: &dyn Geometry) -> bool {
+        let im = self.relate(other);
+        match (self.dimension(), other.dimension()) {
+            (1, 1) => im.matches("1*T***T**"),
+            (da, db) if da == db => im.matches("T*T***T**"),
+            _ => false,
+        }
+    }
+
+    ///Topologically equal : same point set, irrespective of vertex order.
+    ///Named apart from the fuzzy coordinate `Point::equals`
+    fn equals_topo(&self, other: &dyn Geometry) -> bool {
+        self.relate(other).matches("T*F**FFF*")
+    }
 }
 
 #[cfg(test)]
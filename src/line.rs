@@ -1,10 +1,18 @@
 use bbox_2d::MBR;
 use rtree_2d::RTree;
-use crate::{Point, LinearRing, MonoMBR, GeomType, Geometry};
-use crate::{util, segment, parse_wkt};
+use crate::{Point, LinearRing, Polygon, MultiPolygon, MonoMBR, GeomType, GeomError, Geometry};
+use crate::{overlay, clip, simplify, buffer, valid, BufferParams, JoinStyle, ValidityError};
+use crate::{util, segment, noding, parse_wkt};
+use crate::inter::InterPoint;
 use rtree_2d::RTreeObject;
 use std::collections::BTreeSet;
+use std::str::FromStr;
+use std::convert::TryFrom;
+use crate::wkt::WKT;
 use crate::distance;
+use crate::wkb::{self, ByteOrder};
+use crate::geojson;
+use serde_json::Value;
 
 #[derive(Clone, Debug)]
 pub struct LineString {
@@ -12,6 +20,8 @@ pub struct LineString {
     pub bounds: MonoMBR,
     chains: Vec<MonoMBR>,
     index: RTree<MonoMBR>,
+    //distance along the line to each vertex
+    measures: Vec<f64>,
 }
 
 impl LineString {
@@ -22,7 +32,8 @@ impl LineString {
         }
         let (bbox, chains) = util::process_chains(&coordinates);
         let index = RTree::load(chains.clone());
-        LineString { coordinates, bounds: bbox, chains, index }
+        let measures = vertex_measures(&coordinates);
+        LineString { coordinates, bounds: bbox, chains, index, measures }
     }
 
     ///New LineString from vector of points
@@ -32,7 +43,8 @@ impl LineString {
         }
         let (bbox, chains) = util::process_chains(&coordinates);
         let index = RTree::load(chains.clone());
-        LineString { coordinates, bounds: bbox, chains, index }
+        let measures = vertex_measures(&coordinates);
+        LineString { coordinates, bounds: bbox, chains, index, measures }
     }
 
     ///New LineString from vector of points
@@ -41,6 +53,7 @@ impl LineString {
         self.bounds = bbox;
         self.chains = chains;
         self.index = RTree::load(self.chains.clone());
+        self.measures = vertex_measures(&self.coordinates);
         self
     }
 
@@ -51,7 +64,25 @@ impl LineString {
 
     ///Construct from wkt
     pub fn from_wkt(s: &str) -> LineString {
-        s.into()
+        match LineString::try_from(s) {
+            Ok(ln) => ln,
+            Err(err) => panic!("{}", err)
+        }
+    }
+
+    ///Construct from wkt, returns an error on invalid input
+    pub fn from_wkt_checked(s: &str) -> Result<LineString, GeomError> {
+        LineString::from_str(s)
+    }
+
+    ///Construct from wkb
+    pub fn from_wkb(buf: &[u8]) -> Result<LineString, GeomError> {
+        LineString::try_from(wkb::parse_wkb(buf)?)
+    }
+
+    ///Construct from GeoJSON geometry object
+    pub fn from_geojson(s: &str) -> Result<LineString, GeomError> {
+        LineString::try_from(geojson::parse_geojson(s)?)
     }
 
     ///As array
@@ -61,11 +92,26 @@ impl LineString {
 
     ///WKT
     pub fn wkt(&self) -> String {
-        format!("LINESTRING({})", self.coordinates
+        format!("LINESTRING({})", self.fmt_coords())
+    }
+
+    ///Coordinates as wkt string : x y,x y,...
+    pub fn fmt_coords(&self) -> String {
+        self.coordinates
             .iter()
             .map(|pt| pt.fmt_xy())
             .collect::<Vec<_>>()
-            .join(","))
+            .join(",")
+    }
+
+    ///Finite coordinates, no repeated points and at least 2 distinct points
+    pub fn is_valid(&self) -> bool {
+        self.validation_errors().is_empty()
+    }
+
+    ///Reasons self is invalid, empty if valid
+    pub fn validation_errors(&self) -> Vec<ValidityError> {
+        valid::line_errors(&self.coordinates)
     }
 
     ///Length of linestring
@@ -88,6 +134,110 @@ impl LineString {
     }
 }
 
+//linear referencing
+impl LineString {
+    ///Point at distance along self from its start, clamped to the ends of self
+    pub fn interpolate(&self, distance: f64) -> Point {
+        let (i, t) = self.position(distance);
+        self.point_on_segment(i, t)
+    }
+
+    ///Point at a fraction of the length of self, 0 at the start and 1 at the end
+    pub fn interpolate_normalized(&self, fraction: f64) -> Point {
+        self.interpolate(fraction * self.length())
+    }
+
+    ///Distance along self to the position closest to pt, the first one where
+    ///several are equally close ; NaN for a point or line with coordinates that
+    ///are not finite
+    pub fn locate_point(&self, pt: &Point) -> f64 {
+        if !(pt.x.is_finite() && pt.y.is_finite()) {
+            return f64::NAN;
+        }
+        let q = MBR::new_from_pt(pt.as_array());
+        let mbr = self.bounds.mbr;
+        let step = mbr.width().max(mbr.height()) / self.coordinates.len() as f64;
+        //(square distance, segment, fraction along segment) of the closest position
+        let mut best = (f64::INFINITY, 0usize, 0.0);
+        //widen a box around pt until it holds a segment, then search once more within
+        //the distance to it, no segment outside that box can be closer
+        let mut r = mbr.distance(&q) + step;
+        let mut settled = false;
+        loop {
+            if !r.is_finite() {
+                return f64::NAN;
+            }
+            let bx = MBR::new(pt.x - r, pt.y - r, pt.x + r, pt.y + r);
+            for chain in self.index.search(&MonoMBR::new_mono(bx).envelope()) {
+                if chain.mbr.distance_square(&q) > best.0 {
+                    continue;
+                }
+                for i in chain.i..chain.j {
+                    let (a, b) = (self.coordinates[i], self.coordinates[i + 1]);
+                    let t = if a.equals(&b) { 0.0 } else { overlay::param(&a, &b, pt).clamp(0.0, 1.0) };
+                    let c = self.point_on_segment(i, t);
+                    let d = (c.x - pt.x) * (c.x - pt.x) + (c.y - pt.y) * (c.y - pt.y);
+                    if d < best.0 || (d == best.0 && i < best.1) {
+                        best = (d, i, t);
+                    }
+                }
+            }
+            if settled || best.0 <= r * r {
+                break;
+            }
+            settled = best.0.is_finite();
+            r = if settled { best.0.sqrt() } else { 2.0 * r + f64::EPSILON };
+        }
+        let (_, i, t) = best;
+        self.measures[i] + t * (self.measures[i + 1] - self.measures[i])
+    }
+
+    ///Part of self between distances start and end along it, clamped to the ends of
+    ///self ; reversed where end comes before start
+    pub fn substring(&self, start: f64, end: f64) -> LineString {
+        if end < start {
+            let sub = self.substring(end, start);
+            return LineString::new(sub.coordinates.into_iter().rev().collect());
+        }
+        let (i, s) = self.position(start);
+        let (j, t) = self.position(end);
+        let mut coords = vec![self.point_on_segment(i, s)];
+        for pt in self.coordinates[i + 1..=j].iter().chain(Some(&self.point_on_segment(j, t))) {
+            if !pt.equals(&coords[coords.len() - 1]) {
+                coords.push(*pt);
+            }
+        }
+        if coords.len() == 1 {
+            coords.push(coords[0]);
+        }
+        LineString::new(coords)
+    }
+
+    //segment and fraction along it at distance from the start, found by a binary
+    //search of the vertex measures
+    fn position(&self, distance: f64) -> (usize, f64) {
+        let n = self.coordinates.len() - 1;
+        if distance.is_nan() || distance <= 0.0 {
+            return (0, 0.0);
+        }
+        //first vertex at or past distance, the segment before it has some length
+        let k = self.measures.partition_point(|&m| m < distance);
+        if k > n {
+            return (n - 1, 1.0);
+        }
+        let (m0, m1) = (self.measures[k - 1], self.measures[k]);
+        (k - 1, (distance - m0) / (m1 - m0))
+    }
+
+    fn point_on_segment(&self, i: usize, t: f64) -> Point {
+        let (a, b) = (self.coordinates[i], self.coordinates[i + 1]);
+        if t >= 1.0 {
+            return b;
+        }
+        Point::new(a.x + t * (b.x - a.x), a.y + t * (b.y - a.y))
+    }
+}
+
 //linear relate
 impl LineString {
     ///Checks if line intersects other{LineString}
@@ -184,6 +334,100 @@ impl LineString {
         ptset.into_iter().collect()
     }
 
+    ///Points where self meets itself other than at the vertex shared by consecutive
+    ///segments, the endpoints of a closed line do not count ; the inter flags of each
+    ///point are those of the first pair of segments found meeting there
+    pub fn self_intersections(&self) -> Vec<InterPoint> {
+        let ptset: BTreeSet<InterPoint> = noding::intersections(std::slice::from_ref(self))
+            .into_iter()
+            .map(|x| x.point)
+            .collect();
+        ptset.into_iter().collect()
+    }
+
+    ///Self does not meet itself, except at the endpoints of a closed line
+    pub fn is_simple(&self) -> bool {
+        self.self_intersections().is_empty()
+    }
+
+    ///Ordered pieces of self inside polygon, holes excluded, boundary included
+    pub fn clip_by_polygon(&self, polygon: &Polygon) -> Vec<LineString> {
+        overlay::clip_line(self, polygon, true)
+    }
+
+    ///Ordered pieces of self outside polygon, inside holes included
+    pub fn difference(&self, polygon: &Polygon) -> Vec<LineString> {
+        overlay::clip_line(self, polygon, false)
+    }
+
+    ///Douglas-Peucker simplification, vertices within tolerance of the simplified line are dropped
+    pub fn simplify(&self, tolerance: f64) -> LineString {
+        LineString::new(simplify::douglas_peucker(&self.coordinates, tolerance))
+    }
+
+    ///Visvalingam-Whyatt simplification, vertices with an effective area below threshold are dropped
+    pub fn simplify_vw(&self, area_threshold: f64) -> LineString {
+        LineString::new(simplify::visvalingam(&self.coordinates, area_threshold))
+    }
+
+    ///Area within distance of line, a closed line is buffered without caps
+    pub fn buffer(&self, distance: f64, params: BufferParams) -> MultiPolygon {
+        buffer::buffer_line(&self.coordinates, distance, &params)
+    }
+
+    ///Offset of self at distance, to the left (see `Point::side_of`) for a positive
+    ///distance and to the right for a negative one ; loops at sharp turns are removed
+    pub fn offset_curve(&self, distance: f64, join: JoinStyle) -> Vec<LineString> {
+        let quadrant_segments = BufferParams::default().quadrant_segments;
+        buffer::offset_curve(&self.coordinates, distance, join, quadrant_segments)
+    }
+
+    ///Pieces of self inside the box
+    pub fn clip_to_mbr(&self, mbr: &MBR) -> Vec<LineString> {
+        clip::clip_line(self, mbr)
+    }
+
+    ///Index pairs (i, j) of segments of self and other whose bounds intersect,
+    ///segment i spans coordinates i and i + 1
+    pub fn segment_pairs(&self, other: &LineString) -> Vec<(usize, usize)> {
+        let mut pairs = BTreeSet::new();
+        if self.bounds.mbr.disjoint(&other.bounds.mbr) {
+            return Vec::new();
+        }
+        for ibox in self.index.search(&other.bounds.envelope()) {
+            for qbox in other.index.search(&ibox.envelope()) {
+                let inter = ibox.mbr.intersection(&qbox.mbr).unwrap();
+                for i in ibox.i..ibox.j {
+                    let (a0, a1) = (self.coordinates[i], self.coordinates[i + 1]);
+                    if !inter.intersects_bounds(&a0.as_array(), &a1.as_array()) {
+                        continue;
+                    }
+                    let abox = MBR::new_from_bounds(a0.as_array(), a1.as_array());
+                    for j in qbox.i..qbox.j {
+                        let (b0, b1) = (other.coordinates[j], other.coordinates[j + 1]);
+                        if abox.intersects_bounds(&b0.as_array(), &b1.as_array()) {
+                            pairs.insert((i, j));
+                        }
+                    }
+                }
+            }
+        }
+        pairs.into_iter().collect()
+    }
+
+    ///Indices of segments of self whose bounds intersect mbr, in order
+    pub(crate) fn segments_in(&self, mbr: &MBR) -> Vec<usize> {
+        let mut found = vec![];
+        for chain in self.index.search(&MonoMBR::new_mono(*mbr).envelope()) {
+            for i in chain.i..chain.j {
+                if mbr.intersects_bounds(&self.coordinates[i].as_array(), &self.coordinates[i + 1].as_array()) {
+                    found.push(i);
+                }
+            }
+        }
+        found.sort_unstable();
+        found
+    }
 
     //line intersect polygon rings
     pub fn intersection_polygon_rings(&self, rings: &Vec<LinearRing>) -> Vec<Point> {
@@ -307,6 +551,16 @@ impl Geometry for LineString {
         self.wkt()
     }
 
+    fn wkb_bytes(&self, byte_order: ByteOrder) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(9 + 16 * self.coordinates.len());
+        wkb::write_linestring(&mut buf, byte_order, &self.coordinates);
+        buf
+    }
+
+    fn geojson_value(&self) -> Value {
+        geojson::geometry_value("LineString", geojson::positions_value(&self.coordinates))
+    }
+
     fn geom_type(&self) -> GeomType {
         GeomType::LineString
     }
@@ -314,6 +568,8 @@ impl Geometry for LineString {
     fn intersects(&self, other: &dyn Geometry) -> bool {
         if self.bounds.mbr.disjoint(&other.bbox()) {
             false
+        } else if other.geom_type().is_multi() {
+            other.intersects(self)
         } else if other.geom_type().is_polygon() {
             self.intersects_polygon(other.linear_rings())
         } else {
@@ -331,7 +587,9 @@ impl Geometry for LineString {
 
     //Checks if pt intersection other geometry
     fn intersection(&self, other: &dyn Geometry) -> Vec<Point> {
-        if other.geom_type().is_polygon() {
+        if other.geom_type().is_multi() {
+            other.intersection(self)
+        } else if other.geom_type().is_polygon() {
             self.intersection_polygon_rings(other.linear_rings())
         } else {
             //assume as_linear of other is > 1
@@ -356,25 +614,42 @@ impl Geometry for LineString {
 
 
 
-impl From<&str> for LineString {
-    fn from(wkt_str: &str) -> Self {
-        let o = parse_wkt(wkt_str);
+impl FromStr for LineString {
+    type Err = GeomError;
+    fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
+        LineString::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl TryFrom<WKT> for LineString {
+    type Error = GeomError;
+    fn try_from(o: WKT) -> Result<Self, Self::Error> {
         match o.geom_type {
             GeomType::LineString => {
-                LineString::new(o.coordinates[0].clone())
-            }
-            _ => {
-                let msg = if o.success {
-                    format!("invalid wkt string, expected LINESTRING, got : {}", o.geom_type)
-                } else {
-                    format!("parser error : {}", o.message)
-                };
-                panic!(msg)
+                let coordinates = o.coordinates.into_iter().next().unwrap_or_default();
+                if coordinates.len() < 2 {
+                    return Err(GeomError::TooFewCoordinates {
+                        expected: 2,
+                        found: coordinates.len(),
+                    });
+                }
+                Ok(LineString::new(coordinates))
             }
+            _ => Err(GeomError::WrongGeomType {
+                expected: GeomType::LineString,
+                found: o.geom_type,
+            })
         }
     }
 }
 
+impl TryFrom<&str> for LineString {
+    type Error = GeomError;
+    fn try_from(wkt_str: &str) -> Result<Self, Self::Error> {
+        LineString::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
 impl From<Vec<Point>> for LineString {
     fn from(coordinates: Vec<Point>) -> Self {
         LineString::from_vec(coordinates)
@@ -409,3 +684,14 @@ impl std::fmt::Display for LineString {
     }
 }
 
+//distance along coords to each of its vertices
+fn vertex_measures(coords: &[Point]) -> Vec<f64> {
+    let mut dist = 0.0;
+    let mut measures = Vec::with_capacity(coords.len());
+    measures.push(dist);
+    for w in coords.windows(2) {
+        dist += w[0].point_distance(&w[1]);
+        measures.push(dist);
+    }
+    measures
+}
@@ -0,0 +1,77 @@
+use crate::{Point, LineString};
+use std::collections::BTreeMap;
+
+///Lines joined end to end into maximal sequences through nodes where exactly two
+///lines meet, pieces are reversed as needed ; zero length lines are dropped
+pub fn line_merge(lines: &[LineString]) -> Vec<LineString> {
+    merge(lines, false)
+}
+
+///Lines joined as in `line_merge` without reversing any, a sequence goes on through
+///nodes where one line ends and one starts
+pub fn line_merge_directed(lines: &[LineString]) -> Vec<LineString> {
+    merge(lines, true)
+}
+
+fn merge(lines: &[LineString], directed: bool) -> Vec<LineString> {
+    let lines: Vec<&Vec<Point>> = lines.iter()
+        .map(|ln| &ln.coordinates)
+        .filter(|coords| coords.iter().any(|pt| !pt.equals(&coords[0])))
+        .collect();
+    //line ends at each node, true where the line starts
+    let mut ends: BTreeMap<Point, Vec<(usize, bool)>> = BTreeMap::new();
+    for (i, coords) in lines.iter().enumerate() {
+        ends.entry(coords[0]).or_default().push((i, true));
+        ends.entry(coords[coords.len() - 1]).or_default().push((i, false));
+    }
+    let through = |pt: &Point| {
+        let e = &ends[pt];
+        e.len() == 2 && (!directed || e[0].1 != e[1].1)
+    };
+
+    let mut used = vec![false; lines.len()];
+    let mut merged = vec![];
+    //sequences from their ends first, lines left over are on closed sequences
+    for closed in [false, true].iter() {
+        for i in 0..lines.len() {
+            if used[i] {
+                continue;
+            }
+            let (a, b) = (&lines[i][0], &lines[i][lines[i].len() - 1]);
+            let forward = if *closed || !through(a) {
+                true
+            } else if !directed && !through(b) {
+                false
+            } else {
+                continue;
+            };
+            used[i] = true;
+            let mut coords = oriented(lines[i], forward);
+            loop {
+                let end = coords[coords.len() - 1];
+                if !through(&end) {
+                    break;
+                }
+                let (j, starts) = match ends[&end].iter().find(|(j, _)| !used[*j]) {
+                    Some(&next) => next,
+                    None => break,
+                };
+                used[j] = true;
+                coords.extend(oriented(lines[j], starts).into_iter().skip(1));
+            }
+            merged.push(LineString::new(coords));
+        }
+    }
+    merged
+}
+
+fn oriented(coords: &[Point], forward: bool) -> Vec<Point> {
+    if forward {
+        coords.to_vec()
+    } else {
+        coords.iter().rev().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,44 @@
+use super::*;
+
+fn lines(wkts: &[&str]) -> Vec<LineString> {
+    wkts.iter().map(|s| LineString::from_wkt(s)).collect()
+}
+
+fn wkts(lns: &[LineString]) -> Vec<String> {
+    lns.iter().map(|ln| ln.wkt()).collect()
+}
+
+#[test]
+fn test_line_merge() {
+    //two point pieces in any order and direction
+    let lns = lines(&["LINESTRING(2 0,3 0)", "LINESTRING(1 0,0 0)", "LINESTRING(3 0,3 1)", "LINESTRING(2 0,1 0)"]);
+    assert_eq!(wkts(&line_merge(&lns)), vec!["LINESTRING(0 0,1 0,2 0,3 0,3 1)"]);
+
+    //sequences stop where three lines meet
+    let lns = lines(&[
+        "LINESTRING(0 0,1 0)", "LINESTRING(1 0,2 0)", "LINESTRING(2 0,3 0)",
+        "LINESTRING(2 0,2 1)", "LINESTRING(2 1,2 2)",
+    ]);
+    assert_eq!(wkts(&line_merge(&lns)), vec![
+        "LINESTRING(0 0,1 0,2 0)", "LINESTRING(2 0,3 0)", "LINESTRING(2 0,2 1,2 2)",
+    ]);
+
+    //closed sequence, and a zero length piece dropped
+    let lns = lines(&[
+        "LINESTRING(0 0,1 0)", "LINESTRING(1 1,1 0)", "LINESTRING(1 1,0 1,0 0)", "LINESTRING(5 5,5 5)",
+    ]);
+    assert_eq!(wkts(&line_merge(&lns)), vec!["LINESTRING(0 0,1 0,1 1,0 1,0 0)"]);
+}
+
+#[test]
+fn test_line_merge_directed() {
+    let lns = lines(&["LINESTRING(1 0,2 0)", "LINESTRING(0 0,1 0)", "LINESTRING(3 0,2 0)", "LINESTRING(3 0,4 0)"]);
+    assert_eq!(wkts(&line_merge_directed(&lns)), vec![
+        "LINESTRING(0 0,1 0,2 0)", "LINESTRING(3 0,2 0)", "LINESTRING(3 0,4 0)",
+    ]);
+    assert_eq!(wkts(&line_merge(&lns)), vec!["LINESTRING(0 0,1 0,2 0,3 0,4 0)"]);
+
+    //directed cycle
+    let lns = lines(&["LINESTRING(1 0,1 1)", "LINESTRING(0 0,1 0)", "LINESTRING(1 1,0 0)"]);
+    assert_eq!(wkts(&line_merge_directed(&lns)), vec!["LINESTRING(1 0,1 1,0 0,1 0)"]);
+}
@@ -0,0 +1,180 @@
+use crate::{Point, LineString, Geometry, GeomType, GeomError, parse_wkt};
+use bbox_2d::MBR;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use std::convert::TryFrom;
+use crate::wkt::WKT;
+use crate::{distance, util};
+use crate::wkb::{self, ByteOrder};
+use crate::geojson;
+use serde_json::Value;
+
+#[derive(Clone, Debug)]
+pub struct MultiLineString(pub Vec<LineString>);
+
+impl MultiLineString {
+    ///Construct from coordinates
+    pub fn new(coordinates: Vec<Vec<Point>>) -> MultiLineString {
+        if coordinates.is_empty() {
+            panic!("a multilinestring must have at least 1 linestring");
+        }
+        MultiLineString(coordinates.into_iter().map(LineString::new).collect())
+    }
+
+    ///Construct from wkt
+    pub fn from_wkt(s: &str) -> MultiLineString {
+        match MultiLineString::try_from(s) {
+            Ok(mln) => mln,
+            Err(err) => panic!("{}", err)
+        }
+    }
+
+    ///Construct from wkt, returns an error on invalid input
+    pub fn from_wkt_checked(s: &str) -> Result<MultiLineString, GeomError> {
+        MultiLineString::from_str(s)
+    }
+
+    ///Construct from wkb
+    pub fn from_wkb(buf: &[u8]) -> Result<MultiLineString, GeomError> {
+        MultiLineString::try_from(wkb::parse_wkb(buf)?)
+    }
+
+    ///Construct from GeoJSON geometry object
+    pub fn from_geojson(s: &str) -> Result<MultiLineString, GeomError> {
+        MultiLineString::try_from(geojson::parse_geojson(s)?)
+    }
+
+    pub fn lines(&self) -> &[LineString] {
+        &self.0
+    }
+
+    pub fn coordinates(&self) -> Vec<Vec<Point>> {
+        self.0.iter().map(|ln| ln.coordinates.clone()).collect()
+    }
+
+    ///Length of all linestrings
+    pub fn length(&self) -> f64 {
+        self.0.iter().map(|ln| ln.length()).sum()
+    }
+
+    pub fn wkt(&self) -> String {
+        format!("MULTILINESTRING({})", self.0
+            .iter()
+            .map(|ln| format!("({})", ln.fmt_coords()))
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+}
+
+impl Geometry for MultiLineString {
+    fn bbox(&self) -> MBR {
+        let mut lns = self.0.iter();
+        let mut mbr = match lns.next() {
+            Some(ln) => ln.bbox(),
+            None => return util::empty_mbr(),
+        };
+        for ln in lns {
+            mbr.expand_to_include(&ln.bbox());
+        }
+        mbr
+    }
+
+    fn as_linear(&self) -> Vec<LineString> {
+        self.0.clone()
+    }
+
+    fn wkt_string(&self) -> String {
+        self.wkt()
+    }
+
+    fn wkb_bytes(&self, byte_order: ByteOrder) -> Vec<u8> {
+        let mut buf = Vec::new();
+        wkb::write_header(&mut buf, byte_order, wkb::WKB_MULTILINESTRING);
+        wkb::write_u32(&mut buf, byte_order, self.0.len() as u32);
+        for ln in self.0.iter() {
+            wkb::write_linestring(&mut buf, byte_order, &ln.coordinates);
+        }
+        buf
+    }
+
+    fn geojson_value(&self) -> Value {
+        geojson::geometry_value(
+            "MultiLineString",
+            Value::Array(self.0.iter().map(|ln| geojson::positions_value(&ln.coordinates)).collect()),
+        )
+    }
+
+    fn geom_type(&self) -> GeomType {
+        GeomType::MultiLineString
+    }
+
+    fn intersects(&self, other: &dyn Geometry) -> bool {
+        self.0.iter().any(|ln| ln.intersects(other))
+    }
+
+    fn intersection(&self, other: &dyn Geometry) -> Vec<Point> {
+        let mut ptset = BTreeSet::new();
+        for ln in self.0.iter() {
+            ln.intersection(other).iter()
+                .for_each(|v| { ptset.insert(*v); });
+        }
+        ptset.into_iter().collect()
+    }
+
+    fn distance(&self, other: &dyn Geometry) -> f64 {
+        if self.intersects(other) {
+            0.0
+        } else {
+            distance::dist_as_lines(self.as_linear(), other.as_linear())
+        }
+    }
+}
+
+impl std::fmt::Display for MultiLineString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.wkt())
+    }
+}
+
+impl FromStr for MultiLineString {
+    type Err = GeomError;
+    fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
+        MultiLineString::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl TryFrom<WKT> for MultiLineString {
+    type Error = GeomError;
+    fn try_from(o: WKT) -> Result<Self, Self::Error> {
+        match o.geom_type {
+            GeomType::MultiLineString => {
+                if o.coordinates.is_empty() {
+                    return Err(GeomError::TooFewCoordinates { expected: 2, found: 0 });
+                }
+                for coords in o.coordinates.iter() {
+                    if coords.len() < 2 {
+                        return Err(GeomError::TooFewCoordinates { expected: 2, found: coords.len() });
+                    }
+                }
+                Ok(MultiLineString::new(o.coordinates))
+            }
+            _ => Err(GeomError::WrongGeomType {
+                expected: GeomType::MultiLineString,
+                found: o.geom_type,
+            })
+        }
+    }
+}
+
+impl TryFrom<&str> for MultiLineString {
+    type Error = GeomError;
+    fn try_from(wkt_str: &str) -> Result<Self, Self::Error> {
+        MultiLineString::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl From<Vec<LineString>> for MultiLineString {
+    fn from(lines: Vec<LineString>) -> Self {
+        MultiLineString(lines)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{Point, LineString, Geometry, GeomType, GeomError, parse_wkt};
+use bbox_2d::MBR;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use std::convert::TryFrom;
+use crate::wkt::WKT;
+use crate::{distance, util};
+use crate::wkb::{self, ByteOrder};
+use crate::geojson;
+use serde_json::Value;
+
+#[derive(Clone, Debug)]
+pub struct MultiPoint(pub Vec<Point>);
+
+impl MultiPoint {
+    ///Construct from points
+    pub fn new(coordinates: Vec<Point>) -> MultiPoint {
+        if coordinates.is_empty() {
+            panic!("a multipoint must have at least 1 point");
+        }
+        MultiPoint(coordinates)
+    }
+
+    ///Construct from wkt
+    pub fn from_wkt(s: &str) -> MultiPoint {
+        match MultiPoint::try_from(s) {
+            Ok(mpt) => mpt,
+            Err(err) => panic!("{}", err)
+        }
+    }
+
+    ///Construct from wkt, returns an error on invalid input
+    pub fn from_wkt_checked(s: &str) -> Result<MultiPoint, GeomError> {
+        MultiPoint::from_str(s)
+    }
+
+    ///Construct from wkb
+    pub fn from_wkb(buf: &[u8]) -> Result<MultiPoint, GeomError> {
+        MultiPoint::try_from(wkb::parse_wkb(buf)?)
+    }
+
+    ///Construct from GeoJSON geometry object
+    pub fn from_geojson(s: &str) -> Result<MultiPoint, GeomError> {
+        MultiPoint::try_from(geojson::parse_geojson(s)?)
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.0
+    }
+
+    pub fn wkt(&self) -> String {
+        format!("MULTIPOINT({})", self.0
+            .iter()
+            .map(|pt| format!("({})", pt.fmt_xy()))
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+}
+
+impl Geometry for MultiPoint {
+    fn bbox(&self) -> MBR {
+        let mut pts = self.0.iter();
+        let mut mbr = match pts.next() {
+            Some(pt) => MBR::new_from_pt(pt.as_array()),
+            None => return util::empty_mbr(),
+        };
+        for pt in pts {
+            mbr.expand_to_include_xy(pt.x, pt.y);
+        }
+        mbr
+    }
+
+    fn as_linear(&self) -> Vec<LineString> {
+        self.0.iter().map(|pt| LineString::from_point(*pt)).collect()
+    }
+
+    fn wkt_string(&self) -> String {
+        self.wkt()
+    }
+
+    fn wkb_bytes(&self, byte_order: ByteOrder) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(9 + 21 * self.0.len());
+        wkb::write_header(&mut buf, byte_order, wkb::WKB_MULTIPOINT);
+        wkb::write_u32(&mut buf, byte_order, self.0.len() as u32);
+        for pt in self.0.iter() {
+            wkb::write_point(&mut buf, byte_order, pt);
+        }
+        buf
+    }
+
+    fn geojson_value(&self) -> Value {
+        geojson::geometry_value("MultiPoint", geojson::positions_value(&self.0))
+    }
+
+    fn geom_type(&self) -> GeomType {
+        GeomType::MultiPoint
+    }
+
+    fn intersects(&self, other: &dyn Geometry) -> bool {
+        self.0.iter().any(|pt| pt.intersects(other))
+    }
+
+    fn intersection(&self, other: &dyn Geometry) -> Vec<Point> {
+        let mut ptset = BTreeSet::new();
+        for pt in self.0.iter() {
+            pt.intersection(other).iter()
+                .for_each(|v| { ptset.insert(*v); });
+        }
+        ptset.into_iter().collect()
+    }
+
+    fn distance(&self, other: &dyn Geometry) -> f64 {
+        if self.intersects(other) {
+            0.0
+        } else {
+            distance::dist_as_lines(self.as_linear(), other.as_linear())
+        }
+    }
+}
+
+impl std::fmt::Display for MultiPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.wkt())
+    }
+}
+
+impl FromStr for MultiPoint {
+    type Err = GeomError;
+    fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
+        MultiPoint::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl TryFrom<WKT> for MultiPoint {
+    type Error = GeomError;
+    fn try_from(o: WKT) -> Result<Self, Self::Error> {
+        match o.geom_type {
+            GeomType::MultiPoint => {
+                let coordinates = o.coordinates.into_iter().next().unwrap_or_default();
+                if coordinates.is_empty() {
+                    return Err(GeomError::TooFewCoordinates { expected: 1, found: 0 });
+                }
+                Ok(MultiPoint::new(coordinates))
+            }
+            _ => Err(GeomError::WrongGeomType {
+                expected: GeomType::MultiPoint,
+                found: o.geom_type,
+            })
+        }
+    }
+}
+
+impl TryFrom<&str> for MultiPoint {
+    type Error = GeomError;
+    fn try_from(wkt_str: &str) -> Result<Self, Self::Error> {
+        MultiPoint::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl From<Vec<Point>> for MultiPoint {
+    fn from(coordinates: Vec<Point>) -> Self {
+        MultiPoint::new(coordinates)
+    }
+}
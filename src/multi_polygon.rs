@@ -0,0 +1,182 @@
+use crate::{Point, LineString, Polygon, Geometry, GeomType, GeomError, Topology, parse_wkt};
+use bbox_2d::MBR;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use std::convert::TryFrom;
+use crate::wkt::WKT;
+use crate::{distance, util};
+use crate::wkb::{self, ByteOrder};
+use crate::geojson;
+use serde_json::Value;
+
+#[derive(Clone, Debug)]
+pub struct MultiPolygon(pub Vec<Polygon>);
+
+impl MultiPolygon {
+    ///Construct from coordinates
+    pub fn new(coordinates: Vec<Vec<Vec<Point>>>) -> MultiPolygon {
+        if coordinates.is_empty() {
+            panic!("a multipolygon must have at least 1 polygon");
+        }
+        MultiPolygon(coordinates.into_iter().map(Polygon::new).collect())
+    }
+
+    ///Construct from wkt
+    pub fn from_wkt(s: &str) -> MultiPolygon {
+        match MultiPolygon::try_from(s) {
+            Ok(mply) => mply,
+            Err(err) => panic!("{}", err)
+        }
+    }
+
+    ///Construct from wkt, returns an error on invalid input
+    pub fn from_wkt_checked(s: &str) -> Result<MultiPolygon, GeomError> {
+        MultiPolygon::from_str(s)
+    }
+
+    ///Construct from wkb
+    pub fn from_wkb(buf: &[u8]) -> Result<MultiPolygon, GeomError> {
+        MultiPolygon::try_from(wkb::parse_wkb(buf)?)
+    }
+
+    ///Construct from GeoJSON geometry object
+    pub fn from_geojson(s: &str) -> Result<MultiPolygon, GeomError> {
+        MultiPolygon::try_from(geojson::parse_geojson(s)?)
+    }
+
+    pub fn polygons(&self) -> &[Polygon] {
+        &self.0
+    }
+
+    pub fn coordinates(&self) -> Vec<Vec<Vec<Point>>> {
+        self.0.iter().map(|ply| ply.coordinates()).collect()
+    }
+
+    pub fn wkt(&self) -> String {
+        format!("MULTIPOLYGON({})", self.0
+            .iter()
+            .map(|ply| format!("({})", ply.fmt_rings()))
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+}
+
+impl Geometry for MultiPolygon {
+    fn bbox(&self) -> MBR {
+        let mut plys = self.0.iter();
+        let mut mbr = match plys.next() {
+            Some(ply) => ply.bbox(),
+            None => return util::empty_mbr(),
+        };
+        for ply in plys {
+            mbr.expand_to_include(&ply.bbox());
+        }
+        mbr
+    }
+
+    fn as_linear(&self) -> Vec<LineString> {
+        self.0.iter().flat_map(|ply| ply.as_linear()).collect()
+    }
+
+    fn wkt_string(&self) -> String {
+        self.wkt()
+    }
+
+    fn wkb_bytes(&self, byte_order: ByteOrder) -> Vec<u8> {
+        let mut buf = Vec::new();
+        wkb::write_header(&mut buf, byte_order, wkb::WKB_MULTIPOLYGON);
+        wkb::write_u32(&mut buf, byte_order, self.0.len() as u32);
+        for ply in self.0.iter() {
+            wkb::write_polygon(&mut buf, byte_order, &ply.0);
+        }
+        buf
+    }
+
+    fn geojson_value(&self) -> Value {
+        geojson::geometry_value(
+            "MultiPolygon",
+            Value::Array(self.0.iter().map(|ply| geojson::rings_value(&ply.orient(true).0)).collect()),
+        )
+    }
+
+    fn geom_type(&self) -> GeomType {
+        GeomType::MultiPolygon
+    }
+
+    fn intersects(&self, other: &dyn Geometry) -> bool {
+        self.0.iter().any(|ply| ply.intersects(other))
+    }
+
+    fn intersection(&self, other: &dyn Geometry) -> Vec<Point> {
+        let mut ptset = BTreeSet::new();
+        for ply in self.0.iter() {
+            ply.intersection(other).iter()
+                .for_each(|v| { ptset.insert(*v); });
+        }
+        ptset.into_iter().collect()
+    }
+
+    fn area(&self) -> f64 {
+        self.0.iter().map(|ply| ply.area()).sum()
+    }
+
+    fn topology(&self) -> Topology {
+        Topology { polygons: self.coordinates(), ..Topology::default() }
+    }
+
+    fn distance(&self, other: &dyn Geometry) -> f64 {
+        if self.intersects(other) {
+            0.0
+        } else {
+            distance::dist_as_lines(self.as_linear(), other.as_linear())
+        }
+    }
+}
+
+impl std::fmt::Display for MultiPolygon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.wkt())
+    }
+}
+
+impl FromStr for MultiPolygon {
+    type Err = GeomError;
+    fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
+        MultiPolygon::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl TryFrom<WKT> for MultiPolygon {
+    type Error = GeomError;
+    fn try_from(o: WKT) -> Result<Self, Self::Error> {
+        match o.geom_type {
+            GeomType::MultiPolygon => {
+                if o.geometries.is_empty() {
+                    return Err(GeomError::TooFewCoordinates { expected: 4, found: 0 });
+                }
+                let mut polygons = Vec::with_capacity(o.geometries.len());
+                for ply in o.geometries.into_iter() {
+                    polygons.push(Polygon::try_from(ply)?);
+                }
+                Ok(MultiPolygon(polygons))
+            }
+            _ => Err(GeomError::WrongGeomType {
+                expected: GeomType::MultiPolygon,
+                found: o.geom_type,
+            })
+        }
+    }
+}
+
+impl TryFrom<&str> for MultiPolygon {
+    type Error = GeomError;
+    fn try_from(wkt_str: &str) -> Result<Self, Self::Error> {
+        MultiPolygon::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl From<Vec<Polygon>> for MultiPolygon {
+    fn from(polygons: Vec<Polygon>) -> Self {
+        MultiPolygon(polygons)
+    }
+}
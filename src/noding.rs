@@ -0,0 +1,270 @@
+use crate::{Point, LineString, segment};
+use crate::inter::InterPoint;
+use crate::overlay::{node_point, param};
+use math_util::Feq;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+///Intersection of segment `seg_a` of line `line_a` with segment `seg_b` of line `line_b`,
+///(line_a, seg_a) before (line_b, seg_b). The inter flags of `point` give the vertices
+///of the first segment as SELF_A, SELF_B and of the second as OTHER_A, OTHER_B
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SegmentIntersection {
+    pub point: InterPoint,
+    pub line_a: usize,
+    pub seg_a: usize,
+    pub line_b: usize,
+    pub seg_b: usize,
+}
+
+///Every intersection between segments of lines, found with a Bentley-Ottmann sweep.
+///Consecutive segments of a line meeting only at their shared vertex, or at the
+///endpoints of a closed line, are not reported ; overlaps give both overlap ends
+pub fn intersections(lines: &[LineString]) -> Vec<SegmentIntersection> {
+    let mut sweep = Sweep::new(lines);
+    sweep.run();
+    let mut found = sweep.found;
+    found.sort_by(|a, b| {
+        (a.line_a, a.seg_a, a.line_b, a.seg_b).cmp(&(b.line_a, b.seg_a, b.line_b, b.seg_b))
+            .then(a.point.cmp(&b.point))
+    });
+    found
+}
+
+///Lines split at every point where they meet, themselves included, so that no two
+///pieces cross or touch other than at their ends ; overlapping parts come out once
+pub fn node_lines(lines: &[LineString]) -> Vec<LineString> {
+    node_lines_with_sources(lines).into_iter().map(|(ln, _)| ln).collect()
+}
+
+///Noded pieces as in `node_lines`, each with the sorted indices of the lines it is part of
+pub fn node_lines_with_sources(lines: &[LineString]) -> Vec<(LineString, Vec<usize>)> {
+    let mut splits: Vec<Vec<Vec<Point>>> = lines.iter()
+        .map(|ln| ln.coordinates.windows(2).map(|w| vec![w[0], w[1]]).collect())
+        .collect();
+    let mut nodes = BTreeSet::new();
+    for x in intersections(lines) {
+        splits[x.line_a][x.seg_a].push(x.point.pt);
+        splits[x.line_b][x.seg_b].push(x.point.pt);
+        nodes.insert(x.point.pt);
+    }
+
+    let mut pieces: Vec<(Vec<Point>, Vec<usize>)> = vec![];
+    let mut seen: BTreeMap<Vec<Point>, usize> = BTreeMap::new();
+    for (l, segs) in splits.iter_mut().enumerate() {
+        let coords = &lines[l].coordinates;
+        let mut cur = vec![coords[0]];
+        for (i, pts) in segs.iter_mut().enumerate() {
+            let (a, b) = (coords[i], coords[i + 1]);
+            if a.equals(&b) {
+                continue;
+            }
+            pts.sort_by(|p, q| param(&a, &b, p).partial_cmp(&param(&a, &b, q)).unwrap_or(Ordering::Equal));
+            pts.dedup_by(|p, q| p.equals(q));
+            for pt in pts[1..].iter() {
+                cur.push(*pt);
+                if nodes.contains(pt) {
+                    let piece = std::mem::replace(&mut cur, vec![*pt]);
+                    add_piece(&mut pieces, &mut seen, piece, l);
+                }
+            }
+        }
+        add_piece(&mut pieces, &mut seen, cur, l);
+    }
+    pieces.into_iter().map(|(coords, sources)| (LineString::new(coords), sources)).collect()
+}
+
+//keep the first of pieces equal up to direction, recording every line they come from
+fn add_piece(pieces: &mut Vec<(Vec<Point>, Vec<usize>)>, seen: &mut BTreeMap<Vec<Point>, usize>, piece: Vec<Point>, line: usize) {
+    if piece.len() < 2 {
+        return;
+    }
+    let rev: Vec<Point> = piece.iter().rev().cloned().collect();
+    let key = if rev.cmp(&piece) == Ordering::Less { rev } else { piece.clone() };
+    match seen.get(&key) {
+        Some(&k) => {
+            if !pieces[k].1.contains(&line) {
+                pieces[k].1.push(line);
+            }
+        }
+        None => {
+            seen.insert(key, pieces.len());
+            pieces.push((piece, vec![line]));
+        }
+    }
+}
+
+//segment of a line with its endpoints in sweep order
+struct Seg {
+    line: usize,
+    seg: usize,
+    lo: Point,
+    hi: Point,
+}
+
+impl Seg {
+    //y at the sweep line through x, a vertical segment is taken at y clamped to its extent
+    fn y_at(&self, x: f64, y: f64) -> f64 {
+        if self.lo.x.feq(self.hi.x) {
+            y.max(self.lo.y).min(self.hi.y)
+        } else {
+            self.lo.y + (x - self.lo.x) * (self.hi.y - self.lo.y) / (self.hi.x - self.lo.x)
+        }
+    }
+
+    fn slope(&self) -> f64 {
+        if self.lo.x.feq(self.hi.x) {
+            f64::INFINITY
+        } else {
+            (self.hi.y - self.lo.y) / (self.hi.x - self.lo.x)
+        }
+    }
+}
+
+struct Sweep<'a> {
+    lines: &'a [LineString],
+    segs: Vec<Seg>,
+    //segments starting, ending or crossing at each event point
+    queue: BTreeMap<Point, Vec<usize>>,
+    //segments crossing the sweep line from bottom to top
+    status: Vec<usize>,
+    tested: BTreeSet<(usize, usize)>,
+    found: Vec<SegmentIntersection>,
+}
+
+impl<'a> Sweep<'a> {
+    fn new(lines: &'a [LineString]) -> Sweep<'a> {
+        let mut segs = vec![];
+        let mut queue: BTreeMap<Point, Vec<usize>> = BTreeMap::new();
+        for (line, ln) in lines.iter().enumerate() {
+            for (seg, w) in ln.coordinates.windows(2).enumerate() {
+                let (lo, hi) = match w[0].cmp(&w[1]) {
+                    Ordering::Less => (w[0], w[1]),
+                    Ordering::Greater => (w[1], w[0]),
+                    Ordering::Equal => continue,
+                };
+                queue.entry(lo).or_default().push(segs.len());
+                queue.entry(hi).or_default().push(segs.len());
+                segs.push(Seg { line, seg, lo, hi });
+            }
+        }
+        Sweep { lines, segs, queue, status: vec![], tested: BTreeSet::new(), found: vec![] }
+    }
+
+    fn run(&mut self) {
+        while let Some(&p) = self.queue.keys().next() {
+            let mut ids = self.queue.remove(&p).unwrap_or_default();
+            ids.sort_unstable();
+            ids.dedup();
+            self.handle(&p, &ids);
+        }
+    }
+
+    //segments through p are taken out of the status with those ending at p, every pair
+    //meeting at p is tested, then those going on and those starting at p are put back
+    //in their order just after p
+    fn handle(&mut self, p: &Point, ids: &[usize]) {
+        let (mut lo, mut hi) = self.through(p);
+        //known segments off by rounding from the computed crossing
+        for &s in ids.iter().filter(|&&s| !self.segs[s].lo.equals(p)) {
+            if let Some(k) = self.find(s, lo) {
+                lo = lo.min(k);
+                hi = hi.max(k + 1);
+            }
+        }
+        let mut block: Vec<usize> = self.status.drain(lo..hi).collect();
+        block.extend(ids.iter().filter(|&&s| self.segs[s].lo.equals(p)));
+        for i in 0..block.len() {
+            for j in i + 1..block.len() {
+                self.test(block[i], block[j], p);
+            }
+        }
+        block.retain(|&s| !self.segs[s].hi.equals(p));
+        block.sort_by(|&a, &b| {
+            self.segs[a].slope().partial_cmp(&self.segs[b].slope()).unwrap_or(Ordering::Equal).then(a.cmp(&b))
+        });
+        let n = block.len();
+        self.status.splice(lo..lo, block);
+        let len = self.status.len();
+        if n == 0 {
+            if lo > 0 && lo < len {
+                self.test(self.status[lo - 1], self.status[lo], p);
+            }
+        } else {
+            if lo > 0 {
+                self.test(self.status[lo - 1], self.status[lo], p);
+            }
+            if lo + n < len {
+                self.test(self.status[lo + n - 1], self.status[lo + n], p);
+            }
+        }
+    }
+
+    //status range of segments through p
+    fn through(&self, p: &Point) -> (usize, usize) {
+        let lo = self.status.partition_point(|&t| {
+            let y = self.segs[t].y_at(p.x, p.y);
+            y < p.y && !y.feq(p.y)
+        });
+        let n = self.status[lo..].iter()
+            .take_while(|&&t| self.segs[t].y_at(p.x, p.y).feq(p.y))
+            .count();
+        (lo, lo + n)
+    }
+
+    //index of segment s in status, searched outward from k
+    fn find(&self, s: usize, k: usize) -> Option<usize> {
+        let n = self.status.len();
+        (0..n + 1).flat_map(|d| vec![k + d, k.wrapping_sub(d + 1)])
+            .find(|&i| i < n && self.status[i] == s)
+    }
+
+    //report intersections of a newly adjacent pair, crossings ahead of p are queued
+    fn test(&mut self, a: usize, b: usize, p: &Point) {
+        let (a, b) = if (self.segs[a].line, self.segs[a].seg) < (self.segs[b].line, self.segs[b].seg) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        if !self.tested.insert((a, b)) {
+            return;
+        }
+        let (sa, sb) = (&self.segs[a], &self.segs[b]);
+        let ca = &self.lines[sa.line].coordinates;
+        let cb = &self.lines[sb.line].coordinates;
+        let (a0, a1, b0, b1) = (ca[sa.seg], ca[sa.seg + 1], cb[sb.seg], cb[sb.seg + 1]);
+        let shared = if sa.line == sb.line { shared_vertex(ca, sa.seg, sb.seg) } else { None };
+        for ip in segment::intersection(&a0, &a1, &b0, &b1) {
+            let pt = node_point(&ip, &a0, &a1, &b0, &b1);
+            if shared.is_some_and(|v| v.equals(&pt)) {
+                continue;
+            }
+            if ip.is_intersection() && pt.cmp(p) == Ordering::Greater {
+                self.queue.entry(pt).or_default().extend(vec![a, b]);
+            }
+            self.found.push(SegmentIntersection {
+                point: InterPoint { pt, inter: ip.inter },
+                line_a: sa.line,
+                seg_a: sa.seg,
+                line_b: sb.line,
+                seg_b: sb.seg,
+            });
+        }
+    }
+}
+
+//vertex joining segments i < j of a line when only repeated points lie between them,
+//or the start of a closed line for its first and last segments
+fn shared_vertex(coords: &[Point], i: usize, j: usize) -> Option<Point> {
+    let n = coords.len() - 1;
+    if coords[i + 1..=j].iter().all(|pt| pt.equals(&coords[j])) {
+        Some(coords[j])
+    } else if coords[0].equals(&coords[n]) && coords[j + 1..].iter().chain(coords[..=i].iter()).all(|pt| pt.equals(&coords[0])) {
+        Some(coords[0])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests;
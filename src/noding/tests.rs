@@ -0,0 +1,145 @@
+use super::*;
+use crate::inter::{SELF_B, OTHER_A};
+
+//all pairs of segments, the sweep must find the same intersections
+fn brute_force(lines: &[LineString]) -> Vec<SegmentIntersection> {
+    let segs: Vec<(usize, usize)> = lines.iter().enumerate()
+        .flat_map(|(l, ln)| (0..ln.coordinates.len() - 1).map(move |s| (l, s)))
+        .collect();
+    let mut found = vec![];
+    for (m, &(la, sa)) in segs.iter().enumerate() {
+        for &(lb, sb) in segs[m + 1..].iter() {
+            let (ca, cb) = (&lines[la].coordinates, &lines[lb].coordinates);
+            let (a0, a1, b0, b1) = (ca[sa], ca[sa + 1], cb[sb], cb[sb + 1]);
+            if a0.equals(&a1) || b0.equals(&b1) {
+                continue;
+            }
+            let shared = if la == lb { shared_vertex(ca, sa, sb) } else { None };
+            for ip in segment::intersection(&a0, &a1, &b0, &b1) {
+                let pt = node_point(&ip, &a0, &a1, &b0, &b1);
+                if !shared.is_some_and(|v| v.equals(&pt)) {
+                    let point = InterPoint { pt, inter: ip.inter };
+                    found.push(SegmentIntersection { point, line_a: la, seg_a: sa, line_b: lb, seg_b: sb });
+                }
+            }
+        }
+    }
+    found.sort_by(|a, b| {
+        (a.line_a, a.seg_a, a.line_b, a.seg_b).cmp(&(b.line_a, b.seg_a, b.line_b, b.seg_b))
+            .then(a.point.cmp(&b.point))
+    });
+    found
+}
+
+fn lines(wkts: &[&str]) -> Vec<LineString> {
+    wkts.iter().map(|s| LineString::from_wkt(s)).collect()
+}
+
+#[test]
+fn test_grid_intersections() {
+    let lns = lines(&[
+        "LINESTRING(0 1,4 1)", "LINESTRING(0 2,4 2)", "LINESTRING(4 3,0 3)",
+        "LINESTRING(1 0,1 4)", "LINESTRING(2 4,2 0)", "LINESTRING(3 0,3 4)",
+    ]);
+    let found = intersections(&lns);
+    assert_eq!(found.len(), 9);
+    assert!(found.iter().all(|x| x.point.is_intersection()));
+    assert_eq!(found, brute_force(&lns));
+    let x = found[0];
+    assert_eq!((x.line_a, x.seg_a, x.line_b, x.seg_b), (0, 0, 3, 0));
+    assert_eq!(x.point.pt, Point::new(1., 1.));
+}
+
+#[test]
+fn test_degenerate_intersections() {
+    let lns = lines(&[
+        //three lines through one point
+        "LINESTRING(0 0,4 4)", "LINESTRING(0 4,4 0)", "LINESTRING(2 0,2 4)",
+        //t-junction at a vertex and an overlap
+        "LINESTRING(4 4,6 4,8 4)", "LINESTRING(6 4,6 8)", "LINESTRING(7 4,10 4)",
+        //self crossing with a repeated point
+        "LINESTRING(10 0,14 0,14 0,14 2,12 2,12 -2)",
+        //closed line
+        "LINESTRING(20 0,24 0,24 4,20 0)",
+    ]);
+    let found = intersections(&lns);
+    assert_eq!(found, brute_force(&lns));
+
+    let at = |pt: Point| found.iter().filter(|x| x.point.pt == pt).count();
+    assert_eq!(at(Point::new(2., 2.)), 3);
+    assert_eq!(at(Point::new(20., 0.)), 0);
+    assert_eq!(at(Point::new(14., 0.)), 0);
+    assert_eq!(at(Point::new(12., 0.)), 1);
+    //overlap reported by both its ends
+    let overlap: Vec<&SegmentIntersection> = found.iter().filter(|x| x.line_b == 5).collect();
+    assert_eq!(overlap.iter().map(|x| x.point.pt).collect::<Vec<_>>(), vec![Point::new(7., 4.), Point::new(8., 4.)]);
+    //the t-junction is the start of line 4 and the end of the first segment of line 3
+    let t = found.iter().find(|x| x.line_b == 4).unwrap();
+    assert_eq!((t.line_a, t.seg_a), (3, 0));
+    assert_eq!(t.point.inter, SELF_B | OTHER_A);
+}
+
+#[test]
+fn test_sweep_random_lines() {
+    //linear congruential generator for a repeatable tangle of lines
+    let mut seed = 7u64;
+    let mut rand = move || {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((seed >> 33) % 100) as f64
+    };
+    let mut lns = vec![];
+    for _ in 0..60 {
+        let pts: Vec<Point> = (0..5).map(|_| Point::new(rand(), rand())).collect();
+        lns.push(LineString::new(pts));
+    }
+    //axis parallel lines through shared ordinates
+    for i in 0..10 {
+        let v = (i * 10) as f64;
+        lns.push(LineString::new(vec![Point::new(v, 0.), Point::new(v, 100.)]));
+        lns.push(LineString::new(vec![Point::new(0., v), Point::new(100., v)]));
+    }
+    let found = intersections(&lns);
+    assert!(found.len() > 500);
+    assert_eq!(found, brute_force(&lns));
+}
+
+fn wkts(lns: &[LineString]) -> Vec<String> {
+    lns.iter().map(|ln| ln.wkt()).collect()
+}
+
+#[test]
+fn test_node_lines() {
+    //crossing roads and a t-junction at a vertex
+    let lns = lines(&[
+        "LINESTRING(0 0,4 0,8 0)", "LINESTRING(2 -2,2 2)", "LINESTRING(4 0,4 4,6 6)",
+    ]);
+    assert_eq!(wkts(&node_lines(&lns)), vec![
+        "LINESTRING(0 0,2 0)", "LINESTRING(2 0,4 0)", "LINESTRING(4 0,8 0)",
+        "LINESTRING(2 -2,2 0)", "LINESTRING(2 0,2 2)", "LINESTRING(4 0,4 4,6 6)",
+    ]);
+
+    //overlapping parts come out once with both sources
+    let lns = lines(&["LINESTRING(0 0,10 0)", "LINESTRING(12 0,6 0,6 5)", "LINESTRING(2 0,1 0)"]);
+    let noded = node_lines_with_sources(&lns);
+    let got: Vec<(String, Vec<usize>)> = noded.iter().map(|(ln, src)| (ln.wkt(), src.clone())).collect();
+    assert_eq!(got, vec![
+        ("LINESTRING(0 0,1 0)".to_string(), vec![0]),
+        ("LINESTRING(1 0,2 0)".to_string(), vec![0, 2]),
+        ("LINESTRING(2 0,6 0)".to_string(), vec![0]),
+        ("LINESTRING(6 0,10 0)".to_string(), vec![0, 1]),
+        ("LINESTRING(12 0,10 0)".to_string(), vec![1]),
+        ("LINESTRING(6 0,6 5)".to_string(), vec![1]),
+    ]);
+
+    //self crossing track is split at its crossing, keeping its vertices
+    let lns = lines(&["LINESTRING(0 0,4 0,4 4,2 4,2 -2)"]);
+    assert_eq!(wkts(&node_lines(&lns)), vec![
+        "LINESTRING(0 0,2 0)", "LINESTRING(2 0,4 0,4 4,2 4,2 0)", "LINESTRING(2 0,2 -2)",
+    ]);
+
+    //no crossing left between any two pieces
+    let noded = node_lines(&lines(&[
+        "LINESTRING(0 0,10 10,0 10,10 0)", "LINESTRING(5 -1,5 11)", "LINESTRING(0 5,10 5)",
+    ]));
+    assert!(intersections(&noded).iter().all(|x| !x.point.is_intersection()));
+}
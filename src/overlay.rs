@@ -0,0 +1,441 @@
+use crate::{Point, Polygon, LineString, Location, segment, noding};
+use crate::inter::{InterPoint, SELF_A, SELF_B, OTHER_A, OTHER_B};
+use crate::relate::{locate_in_ring, signed_area};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+
+///Boolean overlay operation on polygons
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum OverlayOp {
+    Intersection,
+    Union,
+    ///Self minus other
+    Difference,
+    SymDifference,
+}
+
+//position of a piece of ring edge relative to the other polygon
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Label {
+    Interior,
+    Exterior,
+    ///On the other boundary, same or opposite direction once both
+    ///polygons are oriented with their interior on the left
+    Shared(bool),
+}
+
+//ring segments of one polygon split at the nodes with the other
+struct Noded<'a> {
+    polygon: &'a Polygon,
+    //ring is clockwise (shell) or counter clockwise (hole) : reversed to keep interior left
+    reversed: Vec<bool>,
+    //split points per ring per segment
+    splits: Vec<Vec<Vec<Point>>>,
+    //shared spans per ring per segment : parameter range and direction
+    shared: Vec<Vec<Vec<(f64, f64, bool)>>>,
+}
+
+impl<'a> Noded<'a> {
+    fn new(polygon: &'a Polygon) -> Noded<'a> {
+        let reversed = polygon.0.iter().enumerate()
+            .map(|(i, r)| (signed_area(r.coordinates()) > 0.0) != (i == 0))
+            .collect();
+        let splits = polygon.0.iter()
+            .map(|r| r.coordinates().windows(2).map(|w| vec![w[0], w[1]]).collect())
+            .collect();
+        let shared = polygon.0.iter()
+            .map(|r| vec![vec![]; r.coordinates().len() - 1])
+            .collect();
+        Noded { polygon, reversed, splits, shared }
+    }
+
+    //oriented direction of segment k of ring r
+    fn direction(&self, r: usize, k: usize) -> Point {
+        let coords = self.polygon.0[r].coordinates();
+        let (a, b) = (coords[k], coords[k + 1]);
+        if self.reversed[r] { Point::new(a.x - b.x, a.y - b.y) } else { Point::new(b.x - a.x, b.y - a.y) }
+    }
+
+    //pieces of ring edges, oriented interior left, labelled against other through its ring indexes
+    fn edges(&self, other: &Polygon) -> Vec<(Point, Point, Label)> {
+        let mut edges = vec![];
+        for (r, ring) in self.splits.iter().enumerate() {
+            let coords = self.polygon.0[r].coordinates();
+            for (k, pts) in ring.iter().enumerate() {
+                let (a, b) = (coords[k], coords[k + 1]);
+                let mut pts = pts.clone();
+                pts.sort_by(|p, q| param(&a, &b, p).partial_cmp(&param(&a, &b, q)).unwrap());
+                pts.dedup_by(|p, q| p.equals(q));
+                for w in pts.windows(2) {
+                    let mid = Point::new((w[0].x + w[1].x) / 2.0, (w[0].y + w[1].y) / 2.0);
+                    let t = param(&a, &b, &mid);
+                    let label = match self.shared[r][k].iter().find(|(t0, t1, _)| *t0 < t && t < *t1) {
+                        Some(&(_, _, same_dir)) => Label::Shared(same_dir),
+                        None => match other.locate_point(&mid) {
+                            Location::Interior => Label::Interior,
+                            Location::Exterior => Label::Exterior,
+                            //on the other boundary without a recorded overlap, numerically degenerate
+                            Location::Boundary => continue,
+                        },
+                    };
+                    if self.reversed[r] {
+                        edges.push((w[1], w[0], label));
+                    } else {
+                        edges.push((w[0], w[1], label));
+                    }
+                }
+            }
+        }
+        edges
+    }
+}
+
+///Overlay of polygons a and b, result polygons have their shell counter clockwise
+///and holes clockwise
+pub fn overlay(a: &Polygon, b: &Polygon, op: OverlayOp) -> Vec<Polygon> {
+    let (mut na, mut nb) = (Noded::new(a), Noded::new(b));
+    node(&mut na, &mut nb);
+
+    let mut edges = vec![];
+    for (p, q, label) in na.edges(b) {
+        let keep = match (op, label) {
+            (OverlayOp::Intersection, Label::Interior) => Some(false),
+            (OverlayOp::Union, Label::Exterior) => Some(false),
+            (OverlayOp::Intersection, Label::Shared(true)) => Some(false),
+            (OverlayOp::Union, Label::Shared(true)) => Some(false),
+            (OverlayOp::Difference, Label::Exterior) => Some(false),
+            (OverlayOp::Difference, Label::Shared(false)) => Some(false),
+            (OverlayOp::SymDifference, Label::Exterior) => Some(false),
+            (OverlayOp::SymDifference, Label::Interior) => Some(true),
+            _ => None,
+        };
+        push_edge(&mut edges, p, q, keep);
+    }
+    //shared edges are taken from a
+    for (p, q, label) in nb.edges(a) {
+        let keep = match (op, label) {
+            (OverlayOp::Intersection, Label::Interior) => Some(false),
+            (OverlayOp::Union, Label::Exterior) => Some(false),
+            (OverlayOp::Difference, Label::Interior) => Some(true),
+            (OverlayOp::SymDifference, Label::Exterior) => Some(false),
+            (OverlayOp::SymDifference, Label::Interior) => Some(true),
+            _ => None,
+        };
+        push_edge(&mut edges, p, q, keep);
+    }
+    build_polygons(trace_rings(&edges))
+}
+
+///Union of a set of polygons, result polygons are disjoint or touch at points.
+///Inputs are ordered along a Z-order curve of their bounds centres, then halves
+///are merged recursively so neighbouring inputs are combined first.
+pub(crate) fn union_all(polygons: Vec<Polygon>) -> Vec<Polygon> {
+    if polygons.len() <= 1 {
+        return polygons;
+    }
+    let centres: Vec<[f64; 2]> = polygons.iter().map(|p| {
+        let bounds = p.bounds();
+        let (ll, ur) = (bounds.ll(), bounds.ur());
+        [(ll[0] + ur[0]) / 2., (ll[1] + ur[1]) / 2.]
+    }).collect();
+    let (mut lo, mut hi) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]);
+    for c in centres.iter() {
+        for k in 0..2 {
+            lo[k] = lo[k].min(c[k]);
+            hi[k] = hi[k].max(c[k]);
+        }
+    }
+    let mut keyed: Vec<(u64, Polygon)> = centres.iter().map(|c| {
+        let cell = |k: usize| if hi[k] > lo[k] {
+            ((c[k] - lo[k]) / (hi[k] - lo[k]) * u32::MAX as f64) as u32
+        } else {
+            0
+        };
+        z_order(cell(0), cell(1))
+    }).zip(polygons).collect();
+    keyed.sort_by_key(|(key, _)| *key);
+    union_sorted(keyed.into_iter().map(|(_, p)| p).collect())
+}
+
+//interleave the bits of x and y
+fn z_order(x: u32, y: u32) -> u64 {
+    let spread = |v: u32| {
+        let mut v = v as u64;
+        v = (v | (v << 16)) & 0x0000_ffff_0000_ffff;
+        v = (v | (v << 8)) & 0x00ff_00ff_00ff_00ff;
+        v = (v | (v << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+        v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+        (v | (v << 1)) & 0x5555_5555_5555_5555
+    };
+    spread(x) | (spread(y) << 1)
+}
+
+fn union_sorted(mut polygons: Vec<Polygon>) -> Vec<Polygon> {
+    if polygons.len() <= 1 {
+        return polygons;
+    }
+    let right = polygons.split_off(polygons.len() / 2);
+    let mut out = union_sorted(polygons);
+    for ply in union_sorted(right) {
+        merge_into(&mut out, ply);
+    }
+    out
+}
+
+//add ply to a set of disjoint polygons, merging every polygon it overlaps
+fn merge_into(out: &mut Vec<Polygon>, ply: Polygon) {
+    let mut cur = ply;
+    let mut i = 0;
+    while i < out.len() {
+        if !out[i].bounds().disjoint(&cur.bounds()) {
+            let mut merged = overlay(&cur, &out[i], OverlayOp::Union);
+            if merged.len() == 1 {
+                cur = merged.pop().unwrap();
+                out.swap_remove(i);
+                //merged polygon may now reach earlier ones
+                i = 0;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    out.push(cur);
+}
+
+//keep edge, reversed or not
+fn push_edge(edges: &mut Vec<(Point, Point)>, p: Point, q: Point, keep: Option<bool>) {
+    match keep {
+        Some(false) => edges.push((p, q)),
+        Some(true) => edges.push((q, p)),
+        None => {}
+    }
+}
+
+//split ring segments of a and b at their intersections, candidate
+//segment pairs come from the monotone chain index of each ring
+fn node(na: &mut Noded, nb: &mut Noded) {
+    let (pa, pb) = (na.polygon, nb.polygon);
+    for (ra, ring_a) in pa.0.iter().enumerate() {
+        for (rb, ring_b) in pb.0.iter().enumerate() {
+            let (ca, cb) = (ring_a.coordinates(), ring_b.coordinates());
+            for (i, j) in ring_a.line_string().segment_pairs(ring_b.line_string()) {
+                let (a0, a1, b0, b1) = (ca[i], ca[i + 1], cb[j], cb[j + 1]);
+                let ips = segment::intersection(&a0, &a1, &b0, &b1);
+                let mut pts = Vec::with_capacity(ips.len());
+                for ip in ips.iter() {
+                    let pt = node_point(ip, &a0, &a1, &b0, &b1);
+                    na.splits[ra][i].push(pt);
+                    nb.splits[rb][j].push(pt);
+                    pts.push(pt);
+                }
+                if pts.len() == 2 {
+                    let (da, db) = (na.direction(ra, i), nb.direction(rb, j));
+                    let same_dir = da.x * db.x + da.y * db.y > 0.0;
+                    let (s0, s1) = (param(&a0, &a1, &pts[0]), param(&a0, &a1, &pts[1]));
+                    na.shared[ra][i].push((s0.min(s1), s0.max(s1), same_dir));
+                    let (s0, s1) = (param(&b0, &b1, &pts[0]), param(&b0, &b1, &pts[1]));
+                    nb.shared[rb][j].push((s0.min(s1), s0.max(s1), same_dir));
+                }
+            }
+        }
+    }
+}
+
+///Pieces of line inside polygon (boundary included) or outside, in line order
+pub(crate) fn clip_line(ln: &LineString, ply: &Polygon, inside: bool) -> Vec<LineString> {
+    let coords = &ln.coordinates;
+    let mut splits: Vec<Vec<Point>> = coords.windows(2).map(|w| vec![w[0], w[1]]).collect();
+    let mut shared: Vec<Vec<(f64, f64)>> = vec![vec![]; splits.len()];
+    for ring in ply.0.iter() {
+        let rc = ring.coordinates();
+        for (i, j) in ln.segment_pairs(ring.line_string()) {
+            let (a0, a1, b0, b1) = (coords[i], coords[i + 1], rc[j], rc[j + 1]);
+            let ips = segment::intersection(&a0, &a1, &b0, &b1);
+            let pts: Vec<Point> = ips.iter().map(|ip| node_point(ip, &a0, &a1, &b0, &b1)).collect();
+            if pts.len() == 2 {
+                let (s0, s1) = (param(&a0, &a1, &pts[0]), param(&a0, &a1, &pts[1]));
+                shared[i].push((s0.min(s1), s0.max(s1)));
+            }
+            splits[i].extend(pts);
+        }
+    }
+
+    let mut parts = vec![];
+    let mut cur: Vec<Point> = vec![];
+    for (i, pts) in splits.iter_mut().enumerate() {
+        let (a, b) = (coords[i], coords[i + 1]);
+        pts.sort_by(|p, q| param(&a, &b, p).partial_cmp(&param(&a, &b, q)).unwrap());
+        pts.dedup_by(|p, q| p.equals(q));
+        for w in pts.windows(2) {
+            let mid = Point::new((w[0].x + w[1].x) / 2.0, (w[0].y + w[1].y) / 2.0);
+            let t = param(&a, &b, &mid);
+            let keep = if shared[i].iter().any(|(t0, t1)| *t0 < t && t < *t1) {
+                inside
+            } else {
+                (ply.locate_point(&mid) == Location::Exterior) != inside
+            };
+            if keep {
+                if cur.is_empty() {
+                    cur.push(w[0]);
+                }
+                cur.push(w[1]);
+            } else if !cur.is_empty() {
+                parts.push(LineString::new(std::mem::take(&mut cur)));
+            }
+        }
+    }
+    if !cur.is_empty() {
+        parts.push(LineString::new(cur));
+    }
+    parts
+}
+
+///Points splitting each segment of a line at its self intersections, in order
+///along the segment and starting and ending with the segment ends
+pub(crate) fn node_self(coords: &[Point]) -> Vec<Vec<Point>> {
+    let mut splits: Vec<Vec<Point>> = coords.windows(2).map(|w| vec![w[0], w[1]]).collect();
+    for x in noding::intersections(&[LineString::new(coords.to_vec())]) {
+        splits[x.seg_a].push(x.point.pt);
+        splits[x.seg_b].push(x.point.pt);
+    }
+    for (k, split) in splits.iter_mut().enumerate() {
+        let (a, b) = (coords[k], coords[k + 1]);
+        split.sort_by(|p, q| param(&a, &b, p).partial_cmp(&param(&a, &b, q)).unwrap_or(Ordering::Equal));
+        split.dedup_by(|p, q| p.equals(q));
+    }
+    splits
+}
+
+//node between segments a and b, exact vertices are preferred over computed intersections
+pub(crate) fn node_point(ip: &InterPoint, a0: &Point, a1: &Point, b0: &Point, b1: &Point) -> Point {
+    if ip.inter & SELF_A > 0 {
+        *a0
+    } else if ip.inter & SELF_B > 0 {
+        *a1
+    } else if ip.inter & OTHER_A > 0 {
+        *b0
+    } else if ip.inter & OTHER_B > 0 {
+        *b1
+    } else {
+        ip.pt
+    }
+}
+
+//projection parameter of pt along a, b
+pub(crate) fn param(a: &Point, b: &Point, pt: &Point) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    ((pt.x - a.x) * dx + (pt.y - a.y) * dy) / (dx * dx + dy * dy)
+}
+
+//closed rings from directed edges, at each node the walk turns as far
+//left as possible so rings touching at a node come out separate
+fn trace_rings(edges: &[(Point, Point)]) -> Vec<Vec<Point>> {
+    let mut out: BTreeMap<Point, Vec<usize>> = BTreeMap::new();
+    for (i, (p, _)) in edges.iter().enumerate() {
+        out.entry(*p).or_default().push(i);
+    }
+    let mut used = vec![false; edges.len()];
+    let mut rings = vec![];
+
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+        let (origin, mut cur) = edges[start];
+        let mut prev = origin;
+        let mut ring = vec![origin, cur];
+        let mut closed = false;
+        while !closed {
+            if cur.equals(&origin) {
+                closed = true;
+                continue;
+            }
+            let d = Point::new(cur.x - prev.x, cur.y - prev.y);
+            let next = out.get(&cur).and_then(|ids| {
+                ids.iter().filter(|&&i| !used[i]).max_by(|&&i, &&j| {
+                    turn(&d, &cur, &edges[i].1).partial_cmp(&turn(&d, &cur, &edges[j].1)).unwrap()
+                }).cloned()
+            });
+            match next {
+                Some(i) => {
+                    used[i] = true;
+                    prev = cur;
+                    cur = edges[i].1;
+                    ring.push(cur);
+                }
+                None => break,
+            }
+        }
+        if closed && ring.len() >= 4 {
+            *ring.last_mut().unwrap() = origin;
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+//signed turn angle from direction d to the edge node -> to, left is positive ;
+//going straight back is -PI whatever the sign of zero so it is the last resort
+pub(crate) fn turn(d: &Point, node: &Point, to: &Point) -> f64 {
+    let o = Point::new(to.x - node.x, to.y - node.y);
+    let (cross, dot) = (d.x * o.y - d.y * o.x, d.x * o.x + d.y * o.y);
+    if cross == 0.0 && dot < 0.0 { -PI } else { cross.atan2(dot) }
+}
+
+//counter clockwise rings are shells, holes go to the smallest shell around them.
+//A clockwise ring inside no shell bounds the face outside everything, as when
+//tracing every face of a planar graph, and is dropped
+pub(crate) fn build_polygons(rings: Vec<Vec<Point>>) -> Vec<Polygon> {
+    let mut shells = vec![];
+    let mut holes = vec![];
+    for ring in rings.into_iter().flat_map(split_ring) {
+        let area = signed_area(&ring);
+        if area > 0.0 {
+            shells.push((area, vec![ring]));
+        } else if area < 0.0 {
+            holes.push(ring);
+        }
+    }
+    for hole in holes.into_iter() {
+        let owner = shells.iter_mut()
+            .filter(|(_, rings)| {
+                hole.iter()
+                    .map(|pt| locate_in_ring(&rings[0], pt))
+                    .find(|loc| *loc != Location::Boundary)
+                    == Some(Location::Interior)
+            })
+            .min_by(|(x, _), (y, _)| x.partial_cmp(y).unwrap());
+        if let Some((_, rings)) = owner {
+            rings.push(hole);
+        }
+    }
+    shells.into_iter().map(|(_, rings)| Polygon::new(rings)).collect()
+}
+
+//split a ring passing more than once through a node into simple loops, a hole
+//touching its shell at a point is traced as one ring through that point
+fn split_ring(ring: Vec<Point>) -> Vec<Vec<Point>> {
+    let mut loops = vec![];
+    let mut path: Vec<Point> = vec![];
+    let mut at: BTreeMap<Point, usize> = BTreeMap::new();
+    for pt in ring.into_iter() {
+        if let Some(&k) = at.get(&pt) {
+            let mut lp = path.split_off(k);
+            for p in lp.iter() {
+                at.remove(p);
+            }
+            lp.push(pt);
+            if lp.len() >= 4 {
+                loops.push(lp);
+            }
+        }
+        at.insert(pt, path.len());
+        path.push(pt);
+    }
+    loops
+}
+
+#[cfg(test)]
+mod tests;
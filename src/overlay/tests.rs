@@ -0,0 +1,195 @@
+use super::*;
+use crate::Geometry;
+use math_util::round;
+
+fn area(plys: &[Polygon]) -> f64 {
+    round(plys.iter().map(|p| p.area()).sum(), 9)
+}
+
+fn holes(plys: &[Polygon]) -> usize {
+    plys.iter().map(|p| p.holes().len()).sum()
+}
+
+#[test]
+fn test_overlay_overlapping() {
+    let a = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0))");
+    let b = Polygon::from_wkt("POLYGON((5 5,5 15,15 15,15 5,5 5))");
+    let inter = a.intersection_polygons(&b);
+    assert_eq!((inter.len(), area(&inter)), (1, 25.));
+    assert_eq!(inter[0].bbox().wkt(), Polygon::from_wkt("POLYGON((5 5,5 10,10 10,10 5,5 5))").bbox().wkt());
+    let union = a.union(&b);
+    assert_eq!((union.len(), area(&union), holes(&union)), (1, 175., 0));
+    let diff = a.difference(&b);
+    assert_eq!((diff.len(), area(&diff)), (1, 75.));
+    assert_eq!(area(&b.difference(&a)), 75.);
+    //two L shapes touching at (5 10) and (10 5)
+    let sym = a.sym_difference(&b);
+    assert_eq!((sym.len(), area(&sym)), (2, 150.));
+    //result shells are counter clockwise
+    assert!(signed_area(union[0].shell().coordinates()) > 0.);
+}
+
+#[test]
+fn test_overlay_containment() {
+    let a = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0))");
+    let b = Polygon::from_wkt("POLYGON((2 2,2 8,8 8,8 2,2 2))");
+    let diff = a.difference(&b);
+    assert_eq!((diff.len(), area(&diff), holes(&diff)), (1, 64., 1));
+    assert!(signed_area(diff[0].holes()[0].coordinates()) < 0.);
+    assert!(b.difference(&a).is_empty());
+    assert_eq!(area(&a.union(&b)), 100.);
+    assert_eq!(area(&a.intersection_polygons(&b)), 36.);
+    assert_eq!(area(&a.sym_difference(&b)), 64.);
+
+    //cutting a hole into the hole of a polygon
+    let c = Polygon::from_wkt("POLYGON((4 4,4 6,6 6,6 4,4 4))");
+    let ring = diff[0].difference(&c);
+    assert_eq!((area(&ring), holes(&ring)), (64., 1));
+    let filled = diff[0].union(&b);
+    assert_eq!((filled.len(), area(&filled), holes(&filled)), (1, 100., 0));
+}
+
+#[test]
+fn test_overlay_disjoint_and_adjacent() {
+    let a = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0))");
+    let far = Polygon::from_wkt("POLYGON((20 0,20 10,30 10,30 0,20 0))");
+    assert!(a.intersection_polygons(&far).is_empty());
+    assert_eq!(a.union(&far).len(), 2);
+    assert_eq!(area(&a.difference(&far)), 100.);
+
+    let next = Polygon::from_wkt("POLYGON((10 0,10 10,20 10,20 0,10 0))");
+    assert!(a.intersection_polygons(&next).is_empty());
+    let union = a.union(&next);
+    assert_eq!((union.len(), area(&union)), (1, 200.));
+    assert_eq!(area(&a.difference(&next)), 100.);
+    assert_eq!(a.sym_difference(&next).len(), 1);
+
+    let same = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0))");
+    assert_eq!(area(&a.intersection_polygons(&same)), 100.);
+    assert_eq!(area(&a.union(&same)), 100.);
+    assert!(a.difference(&same).is_empty());
+    assert!(a.sym_difference(&same).is_empty());
+}
+
+#[test]
+fn test_overlay_holes_and_parts() {
+    let a = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0),(4 4,4 6,6 6,6 4,4 4))");
+    let b = Polygon::from_wkt("POLYGON((5 -5,5 15,15 15,15 -5,5 -5))");
+    let inter = a.intersection_polygons(&b);
+    assert_eq!((inter.len(), area(&inter), holes(&inter)), (1, 48., 0));
+    let union = a.union(&b);
+    assert_eq!((union.len(), area(&union), holes(&union)), (1, 248., 1));
+
+    let strip = Polygon::from_wkt("POLYGON((4 -1,6 -1,6 11,4 11,4 -1))");
+    let parts = a.difference(&strip);
+    assert_eq!((parts.len(), area(&parts), holes(&parts)), (2, 80., 0));
+    let cross = a.overlay(&strip, OverlayOp::Intersection);
+    assert_eq!((cross.len(), area(&cross)), (2, 16.));
+}
+
+#[test]
+fn test_overlay_area_invariants() {
+    let a = Polygon::from_wkt("POLYGON((0 0,10 0,5 10,0 0))");
+    let b = Polygon::from_wkt("POLYGON((0 5,5 -5,10 5,3 7,0 5))");
+    let c = Polygon::from_wkt("POLYGON((1 1,9 2,6 9,2 6,1 1),(3 3,5 3,4 5,3 3))");
+    for (p, q) in [(&a, &b), (&b, &c), (&a, &c)].iter() {
+        let (ap, aq) = (p.area(), q.area());
+        let inter = area(&p.intersection_polygons(q));
+        let union = area(&p.union(q));
+        assert!(inter > 0.);
+        assert!((inter + union - ap - aq).abs() < 1e-6);
+        assert!((area(&p.difference(q)) + inter - ap).abs() < 1e-6);
+        assert!((area(&p.sym_difference(q)) - (union - inter)).abs() < 1e-6);
+        assert!((area(&q.intersection_polygons(p)) - inter).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_clip_line_by_polygon() {
+    let ply = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0),(4 4,4 6,6 6,6 4,4 4))");
+    let wkts = |lns: Vec<LineString>| lns.iter().map(|ln| ln.wkt()).collect::<Vec<_>>();
+
+    let road = LineString::from_wkt("LINESTRING(-5 5,15 5)");
+    assert_eq!(wkts(road.clip_by_polygon(&ply)), vec!["LINESTRING(0 5,4 5)", "LINESTRING(6 5,10 5)"]);
+    assert_eq!(
+        wkts(road.difference(&ply)),
+        vec!["LINESTRING(-5 5,0 5)", "LINESTRING(4 5,6 5)", "LINESTRING(10 5,15 5)"],
+    );
+
+    let bend = LineString::from_wkt("LINESTRING(-5 2,5 2,5 8,-5 8)");
+    assert_eq!(
+        wkts(bend.clip_by_polygon(&ply)),
+        vec!["LINESTRING(0 2,5 2,5 4)", "LINESTRING(5 6,5 8,0 8)"],
+    );
+    assert_eq!(
+        wkts(bend.difference(&ply)),
+        vec!["LINESTRING(-5 2,0 2)", "LINESTRING(5 4,5 6)", "LINESTRING(0 8,-5 8)"],
+    );
+
+    //along the boundary : inside the closed polygon
+    let fence = LineString::from_wkt("LINESTRING(-5 0,5 0)");
+    assert_eq!(wkts(fence.clip_by_polygon(&ply)), vec!["LINESTRING(0 0,5 0)"]);
+    assert_eq!(wkts(fence.difference(&ply)), vec!["LINESTRING(-5 0,0 0)"]);
+
+    let outside = LineString::from_wkt("LINESTRING(20 0,30 10)");
+    assert!(outside.clip_by_polygon(&ply).is_empty());
+    assert_eq!(wkts(outside.difference(&ply)), vec!["LINESTRING(20 0,30 10)"]);
+}
+
+fn ring(xy: &[[f64; 2]]) -> Vec<Point> {
+    xy.iter().map(|v| Point::new(v[0], v[1])).collect()
+}
+
+#[test]
+fn test_build_polygons_outer_faces() {
+    let shell = ring(&[[0., 0.], [10., 0.], [10., 10.], [0., 10.], [0., 0.]]);
+    let hole = ring(&[[2., 2.], [2., 4.], [4., 4.], [4., 2.], [2., 2.]]);
+    let outer = ring(&[[0., 0.], [0., 10.], [10., 10.], [10., 0.], [0., 0.]]);
+    let plys = build_polygons(vec![shell.clone(), hole.clone(), outer]);
+    assert_eq!(plys.len(), 1);
+    assert_eq!(area(&plys), 96.);
+    assert_eq!(holes(&plys), 1);
+    let plys = build_polygons(vec![shell, hole]);
+    assert_eq!(area(&plys), 96.);
+}
+
+#[test]
+fn test_build_polygons_unowned_hole() {
+    //a clockwise ring with no shell around it is dropped, the others are kept
+    let hole = ring(&[[2., 2.], [2., 4.], [4., 4.], [4., 2.], [2., 2.]]);
+    assert!(build_polygons(vec![hole.clone()]).is_empty());
+    let far = ring(&[[20., 0.], [30., 0.], [30., 10.], [20., 10.], [20., 0.]]);
+    let plys = build_polygons(vec![hole, far]);
+    assert_eq!(plys.len(), 1);
+    assert_eq!((area(&plys), holes(&plys)), (100., 0));
+}
+
+#[test]
+fn test_turn_reversal_is_last() {
+    let o = Point::new(0., 0.);
+    assert_eq!(turn(&Point::new(1., 0.), &o, &Point::new(-1., 0.)), -PI);
+    assert_eq!(turn(&Point::new(-1., 0.), &o, &Point::new(1., 0.)), -PI);
+    assert_eq!(turn(&Point::new(0., -1.), &o, &Point::new(0., 1.)), -PI);
+    assert_eq!(turn(&Point::new(1., 0.), &o, &Point::new(0., 1.)), PI / 2.);
+    assert_eq!(turn(&Point::new(1., 0.), &o, &Point::new(0., -1.)), -PI / 2.);
+    assert_eq!(turn(&Point::new(1., 0.), &o, &Point::new(2., 0.)), 0.);
+}
+
+#[test]
+fn test_union_all_order() {
+    assert_eq!(z_order(0b11, 0b00), 0b0101);
+    assert_eq!(z_order(0b00, 0b11), 0b1010);
+    assert_eq!(z_order(u32::MAX, u32::MAX), u64::MAX);
+
+    //overlapping squares handed over alternating between two distant rows
+    let square = |x: f64, y: f64| Polygon::from_vec(&ring(&[[x, y], [x + 1.5, y], [x + 1.5, y + 1.], [x, y + 1.], [x, y]]));
+    let mut plys = vec![];
+    for i in 0..20 {
+        plys.push(square(i as f64, 0.));
+        plys.push(square(i as f64, 100.));
+    }
+    let out = union_all(plys);
+    assert_eq!(out.len(), 2);
+    assert_eq!(area(&out), 2. * 20.5);
+    assert_eq!(holes(&out), 0);
+}
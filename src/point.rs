@@ -8,9 +8,16 @@ use side_rel::Side;
 use serde::export::Formatter;
 use serde::export::fmt::Error;
 use std::cmp::Ordering;
-use crate::{Geometry, LineString, GeomType, parse_wkt};
+use std::str::FromStr;
+use std::convert::TryFrom;
+use crate::wkt::WKT;
+use crate::{Geometry, LineString, MultiPolygon, GeomType, GeomError, BufferParams, parse_wkt};
+use crate::buffer;
 use bbox_2d::MBR;
 use crate::distance;
+use crate::wkb::{self, ByteOrder};
+use crate::geojson;
+use serde_json::Value;
 
 
 /// Point is a 2D (x:float, y:float) point type.
@@ -35,7 +42,25 @@ impl Point {
 
     ///Construct new point from wkt
     pub fn from_wkt(s: &str) -> Point {
-        s.into()
+        match Point::try_from(s) {
+            Ok(pt) => pt,
+            Err(err) => panic!("{}", err)
+        }
+    }
+
+    ///Construct new point from wkt, returns an error on invalid input
+    pub fn from_wkt_checked(s: &str) -> Result<Point, GeomError> {
+        Point::from_str(s)
+    }
+
+    ///Construct from wkb
+    pub fn from_wkb(buf: &[u8]) -> Result<Point, GeomError> {
+        Point::try_from(wkb::parse_wkb(buf)?)
+    }
+
+    ///Construct from GeoJSON geometry object
+    pub fn from_geojson(s: &str) -> Result<Point, GeomError> {
+        Point::try_from(geojson::parse_geojson(s)?)
     }
 
     ///Geometry Type
@@ -220,6 +245,11 @@ impl Point {
     pub fn fmt_xy(&self) -> String {
         format!("{} {}", self.x, self.y)
     }
+
+    ///Disc (or square) around point, the join style is not used
+    pub fn buffer(&self, distance: f64, params: BufferParams) -> MultiPolygon {
+        buffer::buffer_point(self, distance, &params)
+    }
 }
 
 
@@ -292,26 +322,36 @@ impl<T> From<Vec<[T; 2]>> for Points where T: NumCast + Copy {
 }
 
 
-impl From<&str> for Point {
-    fn from(wkt_str: &str) -> Self {
-        let o = parse_wkt(wkt_str);
+impl FromStr for Point {
+    type Err = GeomError;
+    fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
+        Point::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl TryFrom<WKT> for Point {
+    type Error = GeomError;
+    fn try_from(o: WKT) -> Result<Self, Self::Error> {
         match o.geom_type {
-            GeomType::Point => {
-                let c = o.coordinates[0][0];
-                Point { x: c.x, y: c.y }
-            }
-            _ => {
-                let msg = if o.success {
-                    format!("invalid wkt string, expected POINT, got : {}", o.geom_type)
-                } else {
-                    format!("parser error : {}", o.message)
-                };
-                panic!(msg)
-            }
+            GeomType::Point => match o.coordinates.first().and_then(|coords| coords.first()) {
+                Some(c) => Ok(Point { x: c.x, y: c.y }),
+                None => Err(GeomError::TooFewCoordinates { expected: 1, found: 0 }),
+            },
+            _ => Err(GeomError::WrongGeomType {
+                expected: GeomType::Point,
+                found: o.geom_type,
+            })
         }
     }
 }
 
+impl TryFrom<&str> for Point {
+    type Error = GeomError;
+    fn try_from(wkt_str: &str) -> Result<Self, Self::Error> {
+        Point::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
 impl Eq for Point {}
 
 impl PartialEq for Point {
@@ -383,6 +423,16 @@ impl Geometry for Point {
         format!("{}", self)
     }
 
+    fn wkb_bytes(&self, byte_order: ByteOrder) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(21);
+        wkb::write_point(&mut buf, byte_order, self);
+        buf
+    }
+
+    fn geojson_value(&self) -> Value {
+        geojson::geometry_value("Point", geojson::position_value(self))
+    }
+
     fn geom_type(&self) -> GeomType {
         GeomType::Point
     }
@@ -19,9 +19,9 @@ fn test_geometry_distance<T:Geometry>(a:&T, b:&T) -> f64{
 }
 #[test]
 fn test_point() {
-    let a:Point = "POINT(3 4)".into();
-    let b:Point = "POINT(3 4)".into();
-    let c:Point = "POINT(0 0)".into();
+    let a = Point::from_wkt("POINT(3 4)");
+    let b = Point::from_wkt("POINT(3 4)");
+    let c = Point::from_wkt("POINT(0 0)");
 
     assert_eq!(a.wkt_string(),  "POINT(3 4)".to_string());
     assert!(a.intersects(&b));
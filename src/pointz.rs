@@ -6,7 +6,8 @@ use std::ops::{Index, IndexMut};
 use serde::export::Formatter;
 use serde::export::fmt::Error;
 use std::cmp::Ordering;
-use crate::Point;
+use crate::{Point, GeomError};
+use crate::wkb::{self, ByteOrder, Dialect};
 
 /// PointZ is a 3D (x:float, y:float, z:float) point type.
 #[derive(Serialize, Deserialize, Copy, Clone, PartialOrd, Debug, Default)]
@@ -51,6 +52,16 @@ impl PointZ {
         Point::new(self.x, self.y)
     }
 
+    ///Construct from wkb point with z ordinate (ISO or EWKB)
+    pub fn from_wkb(buf: &[u8]) -> Result<PointZ, GeomError> {
+        wkb::read_point_z(buf)
+    }
+
+    ///Encode as wkb point with z ordinate
+    pub fn wkb(&self, byte_order: ByteOrder, dialect: Dialect) -> Vec<u8> {
+        wkb::write_point_z(self, byte_order, dialect)
+    }
+
     pub fn fmt_xy(&self) -> String {
         format!("{} {} {}", self.x, self.y, self.z)
     }
@@ -1,7 +1,16 @@
-use crate::{LinearRing, Point, Geometry, LineString, GeomType, parse_wkt};
+use crate::{LinearRing, Point, Geometry, LineString, MultiPolygon, GeomType, GeomError, Location, parse_wkt};
+use crate::{relate, clip, buffer, valid, BufferParams, ValidityError};
+use crate::overlay::{self, OverlayOp};
+use crate::ring::is_ring;
 use bbox_2d::MBR;
 use std::collections::BTreeSet;
+use std::str::FromStr;
+use std::convert::TryFrom;
+use crate::wkt::WKT;
 use crate::distance;
+use crate::wkb::{self, ByteOrder};
+use crate::geojson;
+use serde_json::Value;
 
 #[derive(Clone, Debug)]
 pub struct Polygon(pub Vec<LinearRing>);
@@ -19,7 +28,25 @@ impl Polygon {
 
     ///Construct from wkt
     pub fn from_wkt(s: &str) -> Polygon {
-        s.into()
+        match Polygon::try_from(s) {
+            Ok(ply) => ply,
+            Err(err) => panic!("{}", err)
+        }
+    }
+
+    ///Construct from wkt, returns an error on invalid input
+    pub fn from_wkt_checked(s: &str) -> Result<Polygon, GeomError> {
+        Polygon::from_str(s)
+    }
+
+    ///Construct from wkb
+    pub fn from_wkb(buf: &[u8]) -> Result<Polygon, GeomError> {
+        Polygon::try_from(wkb::parse_wkb(buf)?)
+    }
+
+    ///Construct from GeoJSON geometry object
+    pub fn from_geojson(s: &str) -> Result<Polygon, GeomError> {
+        Polygon::try_from(geojson::parse_geojson(s)?)
     }
 
     pub fn shell(&self) -> &LinearRing {
@@ -38,19 +65,95 @@ impl Polygon {
         self.0.iter().map(|v| v.0.coordinates.clone()).collect()
     }
 
+    ///Location of point : on a shell or hole edge is `Boundary`, inside a hole is `Exterior`
+    pub fn locate_point(&self, pt: &Point) -> Location {
+        if !self.bounds().intersects_xy(pt.x, pt.y) {
+            return Location::Exterior;
+        }
+        relate::locate_in_rings(self.0.iter().map(|r| r.line_string()), pt)
+    }
+
+
+    ///Overlay with other polygon, the result may have holes and several parts
+    pub fn overlay(&self, other: &Polygon, op: OverlayOp) -> Vec<Polygon> {
+        overlay::overlay(self, other, op)
+    }
+
+    ///Area shared by self and other
+    pub fn intersection_polygons(&self, other: &Polygon) -> Vec<Polygon> {
+        self.overlay(other, OverlayOp::Intersection)
+    }
+
+    ///Area covered by self or other
+    pub fn union(&self, other: &Polygon) -> Vec<Polygon> {
+        self.overlay(other, OverlayOp::Union)
+    }
+
+    ///Area of self not covered by other
+    pub fn difference(&self, other: &Polygon) -> Vec<Polygon> {
+        self.overlay(other, OverlayOp::Difference)
+    }
+
+    ///Area covered by exactly one of self and other
+    pub fn sym_difference(&self, other: &Polygon) -> Vec<Polygon> {
+        self.overlay(other, OverlayOp::SymDifference)
+    }
+
+    ///Valid rings, holes inside the shell and apart from each other, connected interior
+    pub fn is_valid(&self) -> bool {
+        self.validation_errors().is_empty()
+    }
+
+    ///Reasons self is invalid, empty if valid
+    pub fn validation_errors(&self) -> Vec<ValidityError> {
+        valid::polygon_errors(&self.0)
+    }
+
+    ///Valid polygons covering the same area : self intersecting rings are split into
+    ///their faces, shell faces merged and hole faces cut out
+    pub fn make_valid(&self) -> Vec<Polygon> {
+        valid::make_valid(self)
+    }
+
+    ///Polygon with the shell wound counter clockwise (clockwise if `exterior_ccw` is false)
+    ///and holes the other way, e.g. `orient(true)` for GeoJSON (RFC 7946)
+    pub fn orient(&self, exterior_ccw: bool) -> Polygon {
+        Polygon(self.0.iter().enumerate().map(|(i, r)| {
+            if r.is_ccw() == ((i == 0) == exterior_ccw) { r.clone() } else { r.reversed() }
+        }).collect())
+    }
+
+    ///Douglas-Peucker simplification of each ring
+    pub fn simplify(&self, tolerance: f64) -> Polygon {
+        Polygon(self.0.iter().map(|r| r.simplify(tolerance)).collect())
+    }
+
+    ///Visvalingam-Whyatt simplification of each ring
+    pub fn simplify_vw(&self, area_threshold: f64) -> Polygon {
+        Polygon(self.0.iter().map(|r| r.simplify_vw(area_threshold)).collect())
+    }
+
+    ///Area within distance of polygon, a negative distance shrinks it
+    pub fn buffer(&self, distance: f64, params: BufferParams) -> MultiPolygon {
+        buffer::buffer_polygon(self, distance, &params)
+    }
+
+    ///Parts of self inside the box, holes preserved ; empty if nothing remains
+    pub fn clip_to_mbr(&self, mbr: &MBR) -> Vec<Polygon> {
+        clip::clip_polygon(self, mbr)
+    }
 
     pub fn wkt(&self) -> String {
-        format!("POLYGON(({}))", self.0
+        format!("POLYGON({})", self.fmt_rings())
+    }
+
+    ///Rings as wkt string : (x y,...),(x y,...)
+    pub fn fmt_rings(&self) -> String {
+        self.0
             .iter()
-            .map(|r| {
-                r.0.coordinates
-                    .iter()
-                    .map(|pt| pt.fmt_xy())
-                    .collect::<Vec<_>>()
-                    .join(",")
-            })
+            .map(|r| format!("({})", r.0.fmt_coords()))
             .collect::<Vec<_>>()
-            .join("),("))
+            .join(",")
     }
 }
 
@@ -74,13 +177,26 @@ impl Geometry for Polygon {
         self.wkt()
     }
 
+    fn wkb_bytes(&self, byte_order: ByteOrder) -> Vec<u8> {
+        let mut buf = Vec::new();
+        wkb::write_polygon(&mut buf, byte_order, &self.0);
+        buf
+    }
+
+    //RFC 7946 : exterior rings counter clockwise, holes clockwise
+    fn geojson_value(&self) -> Value {
+        geojson::geometry_value("Polygon", geojson::rings_value(&self.orient(true).0))
+    }
+
     fn geom_type(&self) -> GeomType {
         GeomType::Polygon
     }
 
     fn intersects(&self, other: &dyn Geometry) -> bool {
         let mut bln = false;
-        if other.geom_type().is_polygon() {
+        if other.geom_type().is_multi() {
+            bln = other.intersects(self);
+        } else if other.geom_type().is_polygon() {
             if self.bbox().intersects(&other.bbox()) {
                 bln = if self.bbox().area() < other.bbox().area() {
                     let ln = self.shell().line_string();
@@ -103,7 +219,9 @@ impl Geometry for Polygon {
 
     fn intersection(&self, other: &dyn Geometry) -> Vec<Point> {
         let mut ptset = BTreeSet::new();
-        if other.geom_type().is_polygon() {
+        if other.geom_type().is_multi() {
+            return other.intersection(self);
+        } else if other.geom_type().is_polygon() {
 
             //other intersect self
             let lns = other.linear_rings();
@@ -155,25 +273,51 @@ impl std::fmt::Display for Polygon {
     }
 }
 
-impl From<&str> for Polygon {
-    fn from(wkt_str: &str) -> Self {
-        let o = parse_wkt(wkt_str);
+impl FromStr for Polygon {
+    type Err = GeomError;
+    fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
+        Polygon::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl TryFrom<WKT> for Polygon {
+    type Error = GeomError;
+    fn try_from(o: WKT) -> Result<Self, Self::Error> {
         match o.geom_type {
             GeomType::Polygon => {
-                Polygon::new(o.coordinates)
-            }
-            _ => {
-                let msg = if o.success {
-                    format!("invalid wkt string, expected POLYGON, got : {}", o.geom_type)
-                } else {
-                    format!("parser error : {}", o.message)
-                };
-                panic!(msg)
+                check_rings(&o.coordinates)?;
+                Ok(Polygon::new(o.coordinates))
             }
+            _ => Err(GeomError::WrongGeomType {
+                expected: GeomType::Polygon,
+                found: o.geom_type,
+            })
         }
     }
 }
 
+impl TryFrom<&str> for Polygon {
+    type Error = GeomError;
+    fn try_from(wkt_str: &str) -> Result<Self, Self::Error> {
+        Polygon::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+//rings must be closed with at least 4 coordinates
+pub(crate) fn check_rings(coordinates: &Vec<Vec<Point>>) -> Result<(), GeomError> {
+    if coordinates.is_empty() {
+        return Err(GeomError::TooFewCoordinates { expected: 4, found: 0 });
+    }
+    for (i, coords) in coordinates.iter().enumerate() {
+        if coords.len() < 4 {
+            return Err(GeomError::TooFewCoordinates { expected: 4, found: coords.len() });
+        }
+        if !is_ring(coords) {
+            return Err(GeomError::UnclosedRing { ring: i });
+        }
+    }
+    Ok(())
+}
 
 //polygon lnr_rings
 fn lnr_rings(coordinates: Vec<Vec<Point>>) -> Vec<LinearRing> {
@@ -0,0 +1,124 @@
+use crate::{Point, LineString, Polygon};
+use crate::overlay::build_polygons;
+use crate::valid::{self, ValidityErrorKind};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+///Polygons built from lines, and the lines that could not be used
+#[derive(Clone, Debug, Default)]
+pub struct PolygonizeResult {
+    ///Faces enclosed by the lines, with holes assigned
+    pub polygons: Vec<Polygon>,
+    ///Lines with an end not joined to any other line, after removing such lines repeatedly
+    pub dangles: Vec<LineString>,
+    ///Lines with the same face on both sides
+    pub cut_edges: Vec<LineString>,
+    ///Closed face boundaries that cross themselves, as from lines that are not noded
+    pub invalid_rings: Vec<LineString>,
+    ///Lines of zero length, or whose every segment is given by an earlier line.
+    ///A segment shared by lines that add others is used once, from the first of them
+    pub duplicates: Vec<LineString>,
+}
+
+///Faces enclosed by a set of noded lines (see `node_lines`), lines meet only at their
+///endpoints. Islands become holes of the face around them and polygons of their own
+pub fn polygonize(lines: &[LineString]) -> PolygonizeResult {
+    let mut res = PolygonizeResult::default();
+
+    //a line adds nothing when it has no length or only repeats segments seen before
+    let mut seen = BTreeSet::new();
+    let mut live: Vec<bool> = lines.iter().map(|ln| {
+        let segs: Vec<(Point, Point)> = ln.coordinates.windows(2)
+            .filter(|w| !w[0].equals(&w[1]))
+            .map(|w| key(w[0], w[1]))
+            .collect();
+        let fresh = segs.iter().any(|s| !seen.contains(s));
+        seen.extend(segs);
+        fresh
+    }).collect();
+    for (ln, _) in lines.iter().zip(live.iter()).filter(|(_, &l)| !l) {
+        res.duplicates.push(ln.clone());
+    }
+
+    //prune lines with a free end, starting from the free ends and following the chains back
+    let mut nodes: BTreeMap<Point, Vec<usize>> = BTreeMap::new();
+    for (i, ln) in lines.iter().enumerate().filter(|(i, _)| live[*i]) {
+        nodes.entry(ln.coordinates[0]).or_default().push(i);
+        nodes.entry(ln.coordinates[ln.coordinates.len() - 1]).or_default().push(i);
+    }
+    let mut free: Vec<Point> = nodes.iter().filter(|(_, ls)| ls.len() == 1).map(|(pt, _)| *pt).collect();
+    while let Some(pt) = free.pop() {
+        let i = match nodes[&pt].as_slice() {
+            &[i] => i,
+            _ => continue,
+        };
+        live[i] = false;
+        res.dangles.push(lines[i].clone());
+        let ln = &lines[i].coordinates;
+        for end in [ln[0], ln[ln.len() - 1]].iter() {
+            let incident = nodes.get_mut(end).unwrap();
+            incident.retain(|&j| j != i);
+            if incident.len() == 1 {
+                free.push(*end);
+            }
+        }
+    }
+
+    //an edge traced both ways around the same face is a cut edge
+    let owner = segment_lines(lines, &live);
+    let faces = segment_faces(&owner);
+    let mut cut = BTreeSet::new();
+    for face in faces.iter() {
+        let edges: BTreeSet<(Point, Point)> = face.windows(2).map(|w| (w[0], w[1])).collect();
+        for &(p, q) in edges.iter().filter(|&&(p, q)| edges.contains(&(q, p))) {
+            cut.insert(owner[&key(p, q)]);
+        }
+    }
+    for &i in cut.iter() {
+        live[i] = false;
+        res.cut_edges.push(lines[i].clone());
+    }
+
+    let faces = if cut.is_empty() { faces } else { segment_faces(&segment_lines(lines, &live)) };
+    let mut rings = vec![];
+    let mut invalid = BTreeSet::new();
+    for face in faces.into_iter() {
+        let crossing = valid::ring_errors(&face).iter().any(|e| e.kind != ValidityErrorKind::RingSelfTouch);
+        if !crossing {
+            rings.push(face);
+            continue;
+        }
+        //both ways round a crossing ring are invalid, it is listed once
+        let mut edges: Vec<(Point, Point)> = face.windows(2).map(|w| key(w[0], w[1])).collect();
+        edges.sort();
+        if invalid.insert(edges) {
+            res.invalid_rings.push(LineString::new(face));
+        }
+    }
+    res.polygons = build_polygons(rings);
+    res
+}
+
+//segments of live lines with the line each comes from
+fn segment_lines(lines: &[LineString], live: &[bool]) -> BTreeMap<(Point, Point), usize> {
+    let mut owner = BTreeMap::new();
+    for (i, ln) in lines.iter().enumerate().filter(|(i, _)| live[*i]) {
+        for w in ln.coordinates.windows(2).filter(|w| !w[0].equals(&w[1])) {
+            owner.entry(key(w[0], w[1])).or_insert(i);
+        }
+    }
+    owner
+}
+
+//faces traced over both directions of the segments
+fn segment_faces(segments: &BTreeMap<(Point, Point), usize>) -> Vec<Vec<Point>> {
+    let edges: Vec<(Point, Point)> = segments.keys().flat_map(|&(p, q)| vec![(p, q), (q, p)]).collect();
+    valid::trace_faces(&edges)
+}
+
+fn key(p: Point, q: Point) -> (Point, Point) {
+    if p.cmp(&q) == Ordering::Less { (p, q) } else { (q, p) }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,85 @@
+use super::*;
+use crate::Geometry;
+
+fn lines(wkts: &[&str]) -> Vec<LineString> {
+    wkts.iter().map(|s| LineString::from_wkt(s)).collect()
+}
+
+fn wkts<T: Geometry>(geoms: &[T]) -> Vec<String> {
+    geoms.iter().map(|g| g.wkt_string()).collect()
+}
+
+#[test]
+fn test_polygonize_faces() {
+    //two squares sharing a side, digitised as pieces
+    let res = polygonize(&lines(&[
+        "LINESTRING(0 0,4 0)", "LINESTRING(4 0,8 0,8 4,4 4)", "LINESTRING(4 4,0 4,0 0)", "LINESTRING(4 0,4 4)",
+    ]));
+    assert_eq!(res.polygons.len(), 2);
+    assert!(res.polygons.iter().all(|p| p.is_valid() && p.area() == 16.));
+    assert!(res.dangles.is_empty() && res.cut_edges.is_empty() && res.invalid_rings.is_empty());
+
+    //lake with an island
+    let res = polygonize(&lines(&["LINESTRING(0 0,10 0,10 10,0 10,0 0)", "LINESTRING(4 4,4 6,6 6,6 4,4 4)"]));
+    let mut plys = res.polygons.clone();
+    plys.sort_by(|a, b| b.area().partial_cmp(&a.area()).unwrap());
+    assert_eq!(wkts(&plys), vec![
+        "POLYGON((0 10,0 0,10 0,10 10,0 10),(4 4,4 6,6 6,6 4,4 4))",
+        "POLYGON((4 6,4 4,6 4,6 6,4 6))",
+    ]);
+
+    //hole touching the face boundary at a node
+    let res = polygonize(&lines(&["LINESTRING(0 0,10 0,10 10,0 10,0 0)", "LINESTRING(0 0,4 2,2 4,0 0)"]));
+    assert_eq!(res.polygons.len(), 2);
+    assert!(res.polygons.iter().all(|p| p.is_valid()));
+    assert_eq!(res.polygons.iter().map(|p| p.area()).sum::<f64>(), 100.);
+}
+
+#[test]
+fn test_polygonize_leftovers() {
+    let res = polygonize(&lines(&[
+        "LINESTRING(0 0,4 0)", "LINESTRING(4 0,4 4)", "LINESTRING(4 4,0 4,0 0)",
+        //dangling chain, pruned end by end
+        "LINESTRING(4 4,6 6)", "LINESTRING(6 6,8 6)",
+        //bridge to a second square
+        "LINESTRING(4 0,10 0)", "LINESTRING(10 0,14 0,14 4,10 4,10 0)",
+        //closed line crossing itself
+        "LINESTRING(20 0,24 4,24 0,20 4,20 0)",
+    ]));
+    assert_eq!(res.polygons.len(), 2);
+    assert_eq!(wkts(&res.dangles), vec!["LINESTRING(6 6,8 6)", "LINESTRING(4 4,6 6)"]);
+    assert_eq!(wkts(&res.cut_edges), vec!["LINESTRING(4 0,10 0)"]);
+    assert_eq!(res.invalid_rings.len(), 1);
+    assert_eq!(res.invalid_rings[0].coordinates.len(), 5);
+
+    //nothing encloses a face
+    let res = polygonize(&lines(&["LINESTRING(0 0,4 0)", "LINESTRING(4 0,4 4)"]));
+    assert!(res.polygons.is_empty());
+    assert_eq!(res.dangles.len(), 2);
+}
+
+#[test]
+fn test_polygonize_duplicates() {
+    let res = polygonize(&lines(&[
+        "LINESTRING(0 0,4 0,4 4)", "LINESTRING(4 4,0 4,0 0)",
+        //zero length, a copy reversed, a part of an earlier line
+        "LINESTRING(2 2,2 2)", "LINESTRING(4 4,4 0,0 0)", "LINESTRING(4 0,4 4)",
+        //copy of a dangle is reported once, not keeping the dangle alive
+        "LINESTRING(4 4,6 6)", "LINESTRING(6 6,4 4)",
+    ]));
+    assert_eq!(res.polygons.len(), 1);
+    assert_eq!(res.polygons[0].area(), 16.);
+    assert_eq!(wkts(&res.duplicates), vec![
+        "LINESTRING(2 2,2 2)", "LINESTRING(4 4,4 0,0 0)", "LINESTRING(4 0,4 4)", "LINESTRING(6 6,4 4)",
+    ]);
+    assert_eq!(wkts(&res.dangles), vec!["LINESTRING(4 4,6 6)"]);
+    assert!(res.cut_edges.is_empty());
+
+    //a long chain is pruned from its free end
+    let chain: Vec<LineString> = (0..500).map(|i| {
+        LineString::new(vec![Point::new(i as f64, 0.), Point::new(i as f64 + 1., 0.)])
+    }).collect();
+    let res = polygonize(&chain);
+    assert_eq!(res.dangles.len(), 500);
+    assert!(res.polygons.is_empty());
+}
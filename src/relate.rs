@@ -0,0 +1,516 @@
+use crate::{Point, LineString, GeomType, GeomError, Geometry, segment};
+use crate::{Polygon, MultiPoint, MultiLineString, MultiPolygon, GeometryCollection};
+use crate::inter::{SELF_A, SELF_B, OTHER_A, OTHER_B};
+use bbox_2d::MBR;
+use std::collections::{BTreeMap, BTreeSet};
+
+///Location of a point relative to a geometry
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
+
+impl Location {
+    fn index(self) -> usize {
+        match self {
+            Location::Interior => 0,
+            Location::Boundary => 1,
+            Location::Exterior => 2,
+        }
+    }
+}
+
+///DE-9IM matrix : rows are interior, boundary and exterior of self,
+///columns those of other. An entry is the dimension of the intersection,
+///`None` when empty (F)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IntersectionMatrix(pub [[Option<u8>; 3]; 3]);
+
+impl IntersectionMatrix {
+    ///Empty matrix : FFFFFFFFF
+    pub fn new() -> IntersectionMatrix {
+        IntersectionMatrix([[None; 3]; 3])
+    }
+
+    pub fn get(&self, a: Location, b: Location) -> Option<u8> {
+        self.0[a.index()][b.index()]
+    }
+
+    ///Raise entry to dimension `dim` if lower
+    pub fn set_at_least(&mut self, a: Location, b: Location, dim: u8) {
+        let v = &mut self.0[a.index()][b.index()];
+        match v {
+            Some(d) if *d >= dim => {}
+            _ => *v = Some(dim),
+        }
+    }
+
+    ///Matrix of other relate self
+    pub fn transpose(&self) -> IntersectionMatrix {
+        let mut im = IntersectionMatrix::new();
+        for i in 0..3 {
+            for j in 0..3 {
+                im.0[j][i] = self.0[i][j];
+            }
+        }
+        im
+    }
+
+    ///Test against a 9 character pattern of `T`, `F`, `*`, `0`, `1` or `2`
+    ///e.g. `T*F**FFF*`. Panics if the pattern is malformed, use `try_matches`
+    ///for patterns that are not literals
+    pub fn matches(&self, pattern: &str) -> bool {
+        match self.try_matches(pattern) {
+            Ok(bln) => bln,
+            Err(err) => panic!("{}", err)
+        }
+    }
+
+    ///Test against a pattern as in `matches`, returns an error at the first
+    ///invalid character or if the pattern is not 9 characters long
+    pub fn try_matches(&self, pattern: &str) -> Result<bool, GeomError> {
+        let invalid = |position: usize| GeomError::Parse {
+            message: format!("invalid DE-9IM pattern : {}", pattern),
+            position,
+        };
+        let mut bln = true;
+        let mut n = 0;
+        for (position, c) in pattern.char_indices() {
+            if n == 9 {
+                return Err(invalid(position));
+            }
+            let v = self.0[n / 3][n % 3];
+            bln &= match c.to_ascii_uppercase() {
+                '*' => true,
+                'T' => v.is_some(),
+                'F' => v.is_none(),
+                '0' => v == Some(0),
+                '1' => v == Some(1),
+                '2' => v == Some(2),
+                _ => return Err(invalid(position)),
+            };
+            n += 1;
+        }
+        if n < 9 {
+            return Err(invalid(pattern.len()));
+        }
+        Ok(bln)
+    }
+}
+
+impl Default for IntersectionMatrix {
+    fn default() -> Self {
+        IntersectionMatrix::new()
+    }
+}
+
+impl std::fmt::Display for IntersectionMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for row in self.0.iter() {
+            for v in row.iter() {
+                match v {
+                    Some(d) => write!(f, "{}", d)?,
+                    None => write!(f, "F")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+///Points, lines and polygons of a geometry ; the input of relate
+#[derive(Clone, Debug, Default)]
+pub struct Topology {
+    pub points: Vec<Point>,
+    pub lines: Vec<Vec<Point>>,
+    ///Rings per polygon, shell first
+    pub polygons: Vec<Vec<Vec<Point>>>,
+}
+
+impl Topology {
+    ///Topology of a single dimension geometry from its linear components
+    pub fn from_linear(geom_type: GeomType, lns: Vec<LineString>) -> Topology {
+        let mut topo = Topology::default();
+        let coords = lns.into_iter().map(|ln| ln.coordinates);
+        match geom_type {
+            GeomType::Point | GeomType::MultiPoint => topo.points = coords.flatten().collect(),
+            GeomType::Polygon => topo.polygons = vec![coords.collect()],
+            _ => topo.lines = coords.collect(),
+        }
+        topo
+    }
+
+    ///Highest dimension of its components
+    pub fn dimension(&self) -> u8 {
+        if !self.polygons.is_empty() {
+            2
+        } else if !self.lines.is_empty() {
+            1
+        } else {
+            0
+        }
+    }
+
+    pub fn extend(&mut self, other: Topology) {
+        self.points.extend(other.points);
+        self.lines.extend(other.lines);
+        self.polygons.extend(other.polygons);
+    }
+
+    ///Simplest geometry of the components : a single part, a multi part
+    ///or a collection of the parts of each dimension
+    pub fn into_geometry(self) -> Box<dyn Geometry> {
+        let Topology { mut points, mut lines, mut polygons } = self;
+        let mut parts: Vec<Box<dyn Geometry>> = Vec::new();
+        match points.len() {
+            0 => {}
+            1 => parts.push(Box::new(points.remove(0))),
+            _ => parts.push(Box::new(MultiPoint(points))),
+        }
+        match lines.len() {
+            0 => {}
+            1 => parts.push(Box::new(LineString::new(lines.remove(0)))),
+            _ => parts.push(Box::new(MultiLineString::new(lines))),
+        }
+        match polygons.len() {
+            0 => {}
+            1 => parts.push(Box::new(Polygon::new(polygons.remove(0)))),
+            _ => parts.push(Box::new(MultiPolygon::new(polygons))),
+        }
+        if parts.len() == 1 {
+            parts.remove(0)
+        } else {
+            Box::new(GeometryCollection(parts))
+        }
+    }
+}
+
+///DE-9IM matrix of a relate b
+pub fn relate(a: &Topology, b: &Topology) -> IntersectionMatrix {
+    let (ga, gb) = (Graph::new(a), Graph::new(b));
+    let mut im = IntersectionMatrix::new();
+    im.set_at_least(Location::Exterior, Location::Exterior, 2);
+
+    let mut nodes: BTreeMap<Point, [Option<Location>; 2]> = BTreeMap::new();
+    for pt in ga.vertices().chain(gb.vertices()) {
+        nodes.entry(*pt).or_insert([None, None]);
+    }
+    edge_locations(&ga, &gb, &mut im, &mut nodes, false);
+    edge_locations(&gb, &ga, &mut im, &mut nodes, true);
+
+    for (pt, known) in nodes.iter() {
+        let la = known[0].unwrap_or_else(|| ga.locate(pt));
+        let lb = known[1].unwrap_or_else(|| gb.locate(pt));
+        im.set_at_least(la, lb, 0);
+    }
+    im
+}
+
+///Location of point in polygon rings, shell first, each ring searched through its index
+pub(crate) fn locate_in_rings<'a, I>(rings: I, pt: &Point) -> Location
+    where I: Iterator<Item=&'a LineString> {
+    rings_location(rings.map(|ring| locate_in_indexed_ring(ring, pt)))
+}
+
+//location in a polygon from the locations in its rings, shell first,
+//taken lazily so holes are only visited inside the shell
+fn rings_location<I: Iterator<Item=Location>>(mut locations: I) -> Location {
+    match locations.next() {
+        Some(Location::Interior) => {}
+        Some(loc) => return loc,
+        None => return Location::Exterior,
+    }
+    for hole in locations {
+        match hole {
+            Location::Interior => return Location::Exterior,
+            Location::Boundary => return Location::Boundary,
+            Location::Exterior => {}
+        }
+    }
+    Location::Interior
+}
+
+///Location of point in a closed ring, exact on the ring boundary
+pub(crate) fn locate_in_ring(coords: &[Point], pt: &Point) -> Location {
+    let mut crossings = 0;
+    for w in coords.windows(2) {
+        match ray_crossing(pt, &w[0], &w[1]) {
+            None => return Location::Boundary,
+            Some(true) => crossings += 1,
+            Some(false) => {}
+        }
+    }
+    if crossings % 2 == 1 { Location::Interior } else { Location::Exterior }
+}
+
+//location of point in a closed ring as in locate_in_ring, the index gives the
+//only segments that can hold the point or cross the ray to its right
+pub(crate) fn locate_in_indexed_ring(ring: &LineString, pt: &Point) -> Location {
+    let ray = MBR::new(pt.x, pt.y, ring.bounds.mbr.ur()[0].max(pt.x), pt.y);
+    let coords = &ring.coordinates;
+    let mut crossings = 0;
+    for i in ring.segments_in(&ray) {
+        match ray_crossing(pt, &coords[i], &coords[i + 1]) {
+            None => return Location::Boundary,
+            Some(true) => crossings += 1,
+            Some(false) => {}
+        }
+    }
+    if crossings % 2 == 1 { Location::Interior } else { Location::Exterior }
+}
+
+//whether segment p1, p2 crosses the ray to the right of pt, None if pt is on the segment
+fn ray_crossing(pt: &Point, p1: &Point, p2: &Point) -> Option<bool> {
+    if on_segment(pt, p1, p2) {
+        return None;
+    }
+    //upward edge : low to high, crosses ray to the right if point is on its left
+    if (p1.y > pt.y) != (p2.y > pt.y) {
+        let (lo, hi) = if p1.y < p2.y { (p1, p2) } else { (p2, p1) };
+        return Some(pt.side_of(lo, hi).is_left());
+    }
+    Some(false)
+}
+
+//point on closed segment a, b
+fn on_segment(pt: &Point, a: &Point, b: &Point) -> bool {
+    pt.x >= a.x.min(b.x) && pt.x <= a.x.max(b.x) &&
+        pt.y >= a.y.min(b.y) && pt.y <= a.y.max(b.y) &&
+        pt.side_of(a, b).is_on()
+}
+
+//signed area, positive if counter clockwise
+pub(crate) fn signed_area(coords: &[Point]) -> f64 {
+    let mut area = 0.0;
+    for w in coords.windows(2) {
+        area += w[0].x * w[1].y - w[1].x * w[0].y;
+    }
+    area * 0.5
+}
+
+//projection parameter of pt along a, b
+fn param(a: &Point, b: &Point, pt: &Point) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    ((pt.x - a.x) * dx + (pt.y - a.y) * dy) / (dx * dx + dy * dy)
+}
+
+struct Edge {
+    a: Point,
+    b: Point,
+    ring: bool,
+    //polygon interior to the left of a -> b
+    interior_left: bool,
+}
+
+struct Graph<'a> {
+    topo: &'a Topology,
+    //every segment of the parts in order, zero length ones included
+    edges: Vec<Edge>,
+    //lines and polygon rings indexed by their monotone chains, with the index of their first edge
+    parts: Vec<(LineString, usize)>,
+    //parts of the lines
+    lines: Vec<usize>,
+    //parts of the rings of each polygon, shell first
+    polygons: Vec<Vec<usize>>,
+    //line end points by the mod 2 rule
+    boundary: BTreeSet<Point>,
+}
+
+impl<'a> Graph<'a> {
+    fn new(topo: &'a Topology) -> Graph<'a> {
+        let mut graph = Graph {
+            topo, edges: vec![], parts: vec![], lines: vec![], polygons: vec![], boundary: BTreeSet::new(),
+        };
+        let mut ends: BTreeMap<Point, usize> = BTreeMap::new();
+        for ln in topo.lines.iter() {
+            if let Some(k) = graph.push_part(ln, false, false) {
+                graph.lines.push(k);
+            }
+            if let (Some(first), Some(last)) = (ln.first(), ln.last()) {
+                *ends.entry(*first).or_insert(0) += 1;
+                *ends.entry(*last).or_insert(0) += 1;
+            }
+        }
+        for rings in topo.polygons.iter() {
+            let mut parts = vec![];
+            for (i, ring) in rings.iter().enumerate() {
+                //shell ccw or hole cw : interior on the left
+                let interior_left = (signed_area(ring) > 0.0) == (i == 0);
+                parts.extend(graph.push_part(ring, true, interior_left));
+            }
+            graph.polygons.push(parts);
+        }
+        graph.boundary = ends.into_iter().filter(|(_, n)| n % 2 == 1).map(|(pt, _)| pt).collect();
+        graph
+    }
+
+    fn push_part(&mut self, coords: &[Point], ring: bool, interior_left: bool) -> Option<usize> {
+        if coords.len() < 2 {
+            return None;
+        }
+        self.parts.push((LineString::new(coords.to_vec()), self.edges.len()));
+        for w in coords.windows(2) {
+            self.edges.push(Edge { a: w[0], b: w[1], ring, interior_left });
+        }
+        Some(self.parts.len() - 1)
+    }
+
+    fn vertices(&self) -> impl Iterator<Item=&Point> {
+        self.topo.points.iter()
+            .chain(self.topo.lines.iter().flatten())
+            .chain(self.topo.polygons.iter().flatten().flatten())
+    }
+
+    fn locate_areal(&self, pt: &Point) -> Location {
+        let mut loc = Location::Exterior;
+        for rings in self.polygons.iter() {
+            match rings_location(rings.iter().map(|&k| locate_in_indexed_ring(&self.parts[k].0, pt))) {
+                Location::Boundary => return Location::Boundary,
+                Location::Interior => loc = Location::Interior,
+                Location::Exterior => {}
+            }
+        }
+        loc
+    }
+
+    fn locate(&self, pt: &Point) -> Location {
+        let loc = self.locate_areal(pt);
+        if loc != Location::Exterior {
+            return loc;
+        }
+        let mbr = MBR::new_from_pt(pt.as_array());
+        let on_line = self.lines.iter().map(|&k| &self.parts[k].0).any(|ln| {
+            ln.segments_in(&mbr).into_iter().any(|i| on_segment(pt, &ln.coordinates[i], &ln.coordinates[i + 1]))
+        });
+        if on_line {
+            return self.line_location(pt);
+        }
+        if self.topo.points.iter().any(|p| p.equals(pt)) {
+            Location::Interior
+        } else {
+            Location::Exterior
+        }
+    }
+
+    //location of a point known to be on edge
+    fn locate_on_edge(&self, edge: &Edge, pt: &Point) -> Location {
+        if edge.ring {
+            return Location::Boundary;
+        }
+        match self.locate_areal(pt) {
+            Location::Exterior => self.line_location(pt),
+            loc => loc,
+        }
+    }
+
+    fn line_location(&self, pt: &Point) -> Location {
+        if self.boundary.contains(pt) { Location::Boundary } else { Location::Interior }
+    }
+}
+
+fn is_degenerate(e: &Edge) -> bool {
+    e.a.equals(&e.b)
+}
+
+//edges of gb with bounds meeting each edge of ga, paired through the monotone chains of their parts
+fn edge_pairs(ga: &Graph, gb: &Graph) -> Vec<Vec<usize>> {
+    let mut pairs = vec![vec![]; ga.edges.len()];
+    for (la, ea) in ga.parts.iter() {
+        for (lb, eb) in gb.parts.iter() {
+            for (i, j) in la.segment_pairs(lb) {
+                pairs[ea + i].push(eb + j);
+            }
+        }
+    }
+    pairs
+}
+
+//Splits edges of ga at their intersections with gb and records the
+//location of each piece and of the faces on either side of polygon edges
+fn edge_locations(
+    ga: &Graph, gb: &Graph, im: &mut IntersectionMatrix,
+    nodes: &mut BTreeMap<Point, [Option<Location>; 2]>, transposed: bool,
+) {
+    let (ia, ib) = if transposed { (1, 0) } else { (0, 1) };
+    let mut set = |la: Location, lb: Location, dim: u8| {
+        if transposed { im.set_at_least(lb, la, dim) } else { im.set_at_least(la, lb, dim) }
+    };
+
+    let pairs = edge_pairs(ga, gb);
+    for (e, others) in ga.edges.iter().zip(pairs.iter()).filter(|(e, _)| !is_degenerate(e)) {
+        let mut splits = vec![e.a, e.b];
+        let mut overlaps: Vec<(f64, f64, &Edge)> = vec![];
+        for o in others.iter().map(|&k| &gb.edges[k]).filter(|o| !is_degenerate(o)) {
+            let ips = segment::intersection(&e.a, &e.b, &o.a, &o.b);
+            let mut pts = Vec::with_capacity(ips.len());
+            for ip in ips.iter() {
+                //prefer exact vertices over computed intersections
+                let pt = if ip.inter & SELF_A > 0 {
+                    e.a
+                } else if ip.inter & SELF_B > 0 {
+                    e.b
+                } else if ip.inter & OTHER_A > 0 {
+                    o.a
+                } else if ip.inter & OTHER_B > 0 {
+                    o.b
+                } else {
+                    ip.pt
+                };
+                let known = nodes.entry(pt).or_insert([None, None]);
+                known[ia] = Some(ga.locate_on_edge(e, &pt));
+                known[ib] = Some(gb.locate_on_edge(o, &pt));
+                pts.push(pt);
+            }
+            if pts.len() == 2 {
+                let (t0, t1) = (param(&e.a, &e.b, &pts[0]), param(&e.a, &e.b, &pts[1]));
+                overlaps.push((t0.min(t1), t0.max(t1), o));
+            }
+            splits.extend(pts);
+        }
+
+        for pt in gb.topo.points.iter() {
+            if on_segment(pt, &e.a, &e.b) {
+                splits.push(*pt);
+            }
+        }
+
+        splits.sort_by(|p, q| param(&e.a, &e.b, p).partial_cmp(&param(&e.a, &e.b, q)).unwrap());
+        splits.dedup_by(|p, q| p.equals(q));
+
+        let la = if e.ring { Location::Boundary } else { Location::Interior };
+        for w in splits.windows(2) {
+            let mid = Point::new((w[0].x + w[1].x) / 2.0, (w[0].y + w[1].y) / 2.0);
+            let t = param(&e.a, &e.b, &mid);
+            let overlap = overlaps.iter().find(|(t0, t1, _)| *t0 < t && t < *t1).map(|o| o.2);
+            let lb = match overlap {
+                Some(o) => gb.locate_on_edge(o, &mid),
+                None => gb.locate(&mid),
+            };
+            set(la, lb, 1);
+
+            if !e.ring {
+                continue;
+            }
+            //faces left and right of the polygon edge
+            for &left in [true, false].iter() {
+                let la_face = if left == e.interior_left { Location::Interior } else { Location::Exterior };
+                let lb_face = match overlap {
+                    Some(o) if o.ring => {
+                        let same_dir = (o.b.x - o.a.x) * (e.b.x - e.a.x) + (o.b.y - o.a.y) * (e.b.y - e.a.y) > 0.0;
+                        if left == (o.interior_left == same_dir) { Location::Interior } else { Location::Exterior }
+                    }
+                    _ => match gb.locate_areal(&mid) {
+                        Location::Boundary => continue,
+                        loc => loc,
+                    },
+                };
+                set(la_face, lb_face, 2);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
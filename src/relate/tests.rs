@@ -0,0 +1,139 @@
+use super::*;
+use crate::{Geometry, Polygon, MultiPolygon, GeometryCollection, Segment, read_wkt};
+
+fn im(a: &str, b: &str) -> String {
+    let (ga, gb) = (read_wkt(a).unwrap(), read_wkt(b).unwrap());
+    let m = ga.relate(gb.as_ref());
+    assert_eq!(gb.relate(ga.as_ref()), m.transpose());
+    m.to_string()
+}
+
+#[test]
+fn test_relate_point() {
+    let ply = "POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,2 8,8 8,8 2,2 2))";
+    assert_eq!(im("POINT(1 5)", ply), "0FFFFF212");
+    assert_eq!(im("POINT(0 5)", ply), "F0FFFF212");
+    assert_eq!(im("POINT(2 5)", ply), "F0FFFF212");
+    assert_eq!(im("POINT(5 5)", ply), "FF0FFF212");
+    assert_eq!(im("POINT(15 5)", ply), "FF0FFF212");
+    assert_eq!(im("POINT(3 4)", "POINT(3 4)"), "0FFFFFFF2");
+    assert_eq!(im("POINT(3 4)", "POINT(4 3)"), "FF0FFF0F2");
+    assert_eq!(im("POINT(0 0)", "LINESTRING(0 0,5 5)"), "F0FFFF102");
+    assert_eq!(im("POINT(2 2)", "LINESTRING(0 0,5 5)"), "0FFFFF102");
+    //closed line string has no boundary
+    assert_eq!(im("POINT(0 0)", "LINESTRING(0 0,0 10,10 10,0 0)"), "0FFFFF1F2");
+}
+
+#[test]
+fn test_relate_line() {
+    let ply = "POLYGON((0 0,0 10,10 10,10 0,0 0))";
+    assert_eq!(im("LINESTRING(-5 5,15 5)", ply), "101FF0212");
+    assert_eq!(im("LINESTRING(0 5,5 5)", ply), "1FF00F212");
+    assert_eq!(im("LINESTRING(0 0,0 10)", ply), "F1FF0F212");
+    assert_eq!(im("LINESTRING(0 0,10 10)", "LINESTRING(0 10,10 0)"), "0F1FF0102");
+    assert_eq!(im("LINESTRING(0 0,10 0)", "LINESTRING(5 0,15 0)"), "1010F0102");
+    assert_eq!(im("LINESTRING(0 0,10 0)", "LINESTRING(10 0,0 0)"), "1FFF0FFF2");
+    assert_eq!(im("LINESTRING(0 0,10 0)", "LINESTRING(10 0,10 10)"), "FF1F00102");
+
+    let seg = Segment::new(Point::new(0., 5.), Point::new(5., 5.));
+    let ply = Polygon::from_wkt(ply);
+    assert_eq!(seg.relate(&ply).to_string(), "1FF00F212");
+}
+
+#[test]
+fn test_relate_polygon() {
+    let a = "POLYGON((0 0,0 10,10 10,10 0,0 0))";
+    assert_eq!(im(a, "POLYGON((5 5,5 15,15 15,15 5,5 5))"), "212101212");
+    assert_eq!(im(a, "POLYGON((10 0,10 10,20 10,20 0,10 0))"), "FF2F11212");
+    assert_eq!(im(a, "POLYGON((10 10,10 20,20 20,20 10,10 10))"), "FF2F01212");
+    assert_eq!(im(a, "POLYGON((0 0,0 10,10 10,10 0,0 0))"), "2FFF1FFF2");
+    assert_eq!(im(a, "POLYGON((0 0,10 0,10 10,0 10,0 0))"), "2FFF1FFF2");
+    assert_eq!(im(a, "POLYGON((2 2,2 8,8 8,8 2,2 2))"), "212FF1FF2");
+    assert_eq!(im(a, "POLYGON((0 0,0 5,5 5,5 0,0 0))"), "212F11FF2");
+    //polygon filling the hole
+    let b = "POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,2 8,8 8,8 2,2 2))";
+    assert_eq!(im(b, "POLYGON((2 2,2 8,8 8,8 2,2 2))"), "FF2F112F2");
+    assert_eq!(im(b, "POLYGON((3 3,3 7,7 7,7 3,3 3))"), "FF2FF1212");
+}
+
+#[test]
+fn test_relate_multi() {
+    let mply = MultiPolygon::from_wkt(
+        "MULTIPOLYGON(((0 0,0 10,10 10,10 0,0 0)),((20 0,20 10,30 10,30 0,20 0)))"
+    );
+    assert_eq!(mply.relate(&Point::new(25., 5.)).to_string(), "0F2FF1FF2");
+    assert!(mply.relate_pattern(&Point::new(25., 5.), "T*****FF*").unwrap());
+    assert!(mply.relate_pattern(&Point::new(25., 5.), "T*****FF").is_err());
+    let lns = read_wkt("MULTILINESTRING((0 0,5 0),(5 0,10 0))").unwrap();
+    //shared end point is interior by the mod 2 rule
+    assert_eq!(lns.relate(&Point::new(5., 0.)).to_string(), "0F1FF0FF2");
+    let gc = GeometryCollection::from_wkt("GEOMETRYCOLLECTION(POINT(25 5),LINESTRING(0 5,5 5))");
+    assert_eq!(gc.relate(&mply).to_string(), "1FF00F212");
+}
+
+#[test]
+fn test_intersection_matrix() {
+    let mut m = IntersectionMatrix::new();
+    assert_eq!(m.to_string(), "FFFFFFFFF");
+    m.set_at_least(Location::Interior, Location::Exterior, 1);
+    m.set_at_least(Location::Interior, Location::Exterior, 0);
+    assert_eq!(m.get(Location::Interior, Location::Exterior), Some(1));
+    assert_eq!(m.transpose().to_string(), "FFFFFF1FF");
+    assert!(m.matches("**T******"));
+    assert!(m.matches("ff1ffffff"));
+    assert!(!m.matches("**0******"));
+    assert_eq!(m.try_matches("**T******"), Ok(true));
+    assert_eq!(m.try_matches("**0******"), Ok(false));
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_pattern() {
+    IntersectionMatrix::new().matches("T*F");
+}
+
+#[test]
+fn test_try_matches_errors() {
+    let m = IntersectionMatrix::new();
+    let err = |pattern: &str, position: usize| Err(GeomError::Parse {
+        message: format!("invalid DE-9IM pattern : {}", pattern),
+        position,
+    });
+    assert_eq!(m.try_matches("T*F"), err("T*F", 3));
+    assert_eq!(m.try_matches("T*F**FFF*F"), err("T*F**FFF*F", 9));
+    assert_eq!(m.try_matches("T*F*x*FFF"), err("T*F*x*FFF", 4));
+    assert_eq!(m.try_matches(""), err("", 0));
+    //multi byte characters are reported at their byte offset
+    assert_eq!(m.try_matches("T*é******"), err("T*é******", 2));
+}
+
+#[test]
+fn test_relate_long_lines() {
+    //zig zags of a thousand vertices crossing each other and a band at every tooth
+    let zig = |y0: f64, y1: f64| -> Vec<Point> {
+        (0..1000).map(|i| Point::new(i as f64, if i % 2 == 0 { y0 } else { y1 })).collect()
+    };
+    let a = Topology { lines: vec![zig(0., 10.)], ..Default::default() };
+    let b = Topology { lines: vec![zig(10., 0.)], ..Default::default() };
+    let ply = Topology {
+        polygons: vec![vec![vec![
+            Point::new(-1., 2.), Point::new(1001., 2.), Point::new(1001., 8.), Point::new(-1., 8.), Point::new(-1., 2.),
+        ]]],
+        ..Default::default()
+    };
+    assert_eq!(relate(&a, &b).to_string(), "0F1FF0102");
+    assert_eq!(relate(&a, &ply).to_string(), "101FF0212");
+}
+
+#[test]
+fn test_locate_in_indexed_rings() {
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 4,6 4,6 6,10 6,10 10,0 10,0 0),(2 2,4 2,4 8,2 8,2 2))");
+    for i in 0..=24 {
+        for j in 0..=24 {
+            let pt = Point::new(i as f64 / 2. - 1., j as f64 / 2. - 1.);
+            let brute = rings_location(ply.0.iter().map(|r| locate_in_ring(r.coordinates(), &pt)));
+            assert_eq!(ply.locate_point(&pt), brute);
+            assert_eq!(ply.shell().locate_point(&pt), locate_in_ring(ply.shell().coordinates(), &pt));
+        }
+    }
+}
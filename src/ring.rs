@@ -1,4 +1,10 @@
-use crate::{LineString, Point, Polygon};
+use crate::{LineString, Point, Polygon, Location, GeomType, GeomError, parse_wkt};
+use crate::{relate, simplify, valid, ValidityError};
+use crate::polygon::check_rings;
+use crate::wkt::WKT;
+use crate::geojson;
+use std::str::FromStr;
+use std::convert::TryFrom;
 use bbox_2d::MBR;
 use math_util::sign_of_det2;
 
@@ -17,6 +23,20 @@ impl LinearRing {
         }
         LinearRing(LineString::new(coordinates))
     }
+
+    ///Construct from wkt
+    pub fn from_wkt(s: &str) -> LinearRing {
+        match LinearRing::try_from(s) {
+            Ok(ring) => ring,
+            Err(err) => panic!("{}", err)
+        }
+    }
+
+    ///Construct from wkt of a closed linestring, returns an error on invalid input
+    pub fn from_wkt_checked(s: &str) -> Result<LinearRing, GeomError> {
+        LinearRing::from_str(s)
+    }
+
     pub fn bbox(&self) -> &MBR {
         &self.0.bounds.mbr
     }
@@ -29,6 +49,21 @@ impl LinearRing {
         &self.0.coordinates
     }
 
+    ///Douglas-Peucker simplification, the ring stays closed with at least 4 coordinates
+    pub fn simplify(&self, tolerance: f64) -> LinearRing {
+        LinearRing::new(simplify::douglas_peucker_ring(self.coordinates(), tolerance))
+    }
+
+    ///Visvalingam-Whyatt simplification, the ring stays closed with at least 4 coordinates
+    pub fn simplify_vw(&self, area_threshold: f64) -> LinearRing {
+        LinearRing::new(simplify::visvalingam_ring(self.coordinates(), area_threshold))
+    }
+
+    ///GeoJSON has no ring type, encoded as a closed LineString
+    pub fn geojson_value(&self) -> serde_json::Value {
+        geojson::geometry_value("LineString", geojson::positions_value(self.coordinates()))
+    }
+
     ///Contains point
     pub fn contains_point(&self, pnt: &Point) -> bool {
         return self.bbox().intersects_xy(pnt.x, pnt.y) &&
@@ -60,9 +95,18 @@ impl LinearRing {
         return self.bbox().intersects_xy(pnt.x, pnt.y) && self.completely_in_ring(pnt);
     }
 
+    ///Location of point, the on edge test uses the exact orientation predicate
+    pub fn locate_point(&self, pnt: &Point) -> Location {
+        if !self.bbox().intersects_xy(pnt.x, pnt.y) {
+            return Location::Exterior;
+        }
+        relate::locate_in_indexed_ring(self.line_string(), pnt)
+    }
+
     ///Test whether a point lies inside a ring.
     ///The ring may be oriented in either direction.
-    ///If the point lies on the ring boundary the result of this method is unspecified.
+    ///If the point lies on the ring boundary the result of this method is unspecified,
+    ///use `locate_point` to classify boundary points.
     ///This algorithm does not attempt to first check the point against the envelope of the ring.
     pub fn completely_in_ring(&self, p: &Point) -> bool {
         // for each segment l = (i-1, i), see if it crosses ray from test point in positive x direction.
@@ -88,6 +132,31 @@ impl LinearRing {
         (crossings % 2) == 1
     }
 
+    ///Closed, at least 4 coordinates, no repeated points and no crossing or touching edges
+    pub fn is_valid(&self) -> bool {
+        self.validation_errors().is_empty()
+    }
+
+    ///Reasons self is invalid, empty if valid
+    pub fn validation_errors(&self) -> Vec<ValidityError> {
+        valid::ring_errors(self.coordinates())
+    }
+
+    ///Shoelace area, positive for a counter clockwise ring and negative for a clockwise one
+    pub fn signed_area(&self) -> f64 {
+        relate::signed_area(self.coordinates())
+    }
+
+    ///Counter clockwise ring
+    pub fn is_ccw(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    ///Ring with vertex order reversed
+    pub fn reversed(&self) -> LinearRing {
+        LinearRing::new(self.coordinates().iter().rev().cloned().collect())
+    }
+
     /// Area of linear ring
     pub  fn area(&self) -> f64 {
         let coords = self.coordinates();
@@ -105,6 +174,36 @@ impl LinearRing {
     }
 }
 
+impl FromStr for LinearRing {
+    type Err = GeomError;
+    fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
+        LinearRing::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl TryFrom<WKT> for LinearRing {
+    type Error = GeomError;
+    fn try_from(o: WKT) -> Result<Self, Self::Error> {
+        match o.geom_type {
+            GeomType::LineString => {
+                check_rings(&o.coordinates)?;
+                let coordinates = o.coordinates.into_iter().next().unwrap_or_default();
+                Ok(LinearRing::new(coordinates))
+            }
+            _ => Err(GeomError::WrongGeomType {
+                expected: GeomType::LineString,
+                found: o.geom_type,
+            })
+        }
+    }
+}
+
+impl TryFrom<&str> for LinearRing {
+    type Error = GeomError;
+    fn try_from(wkt_str: &str) -> Result<Self, Self::Error> {
+        LinearRing::try_from(parse_wkt(wkt_str)?)
+    }
+}
 
 ///Is coordinates Po == Pn
 pub fn is_ring(coordinates: &Vec<Point>) -> bool {
@@ -1,11 +1,18 @@
-use crate::{Point, util, pt, LineString, Geometry, GeomType};
+use crate::{Point, util, pt, LineString, MultiPolygon, Geometry, GeomType, GeomError, BufferParams, parse_wkt};
+use crate::buffer;
 use math_util::{Feq, EPSILON};
 use bbox_2d::MBR;
 use crate::inter::{InterPoint, SELF_A, SELF_B, OTHER_A, OTHER_B};
 use crate::util::{snap_to_zero, snap_to_zero_or_one};
+use crate::wkb::{self, ByteOrder};
+use crate::geojson;
+use serde_json::Value;
 use side_rel::Side;
+use std::str::FromStr;
+use std::convert::TryFrom;
+use crate::wkt::WKT;
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 pub struct Segment {
     pub coordinates: [Point; 2]
 }
@@ -15,6 +22,19 @@ impl Segment {
         Segment { coordinates: [a, b] }
     }
 
+    ///Construct from wkt
+    pub fn from_wkt(s: &str) -> Segment {
+        match Segment::try_from(s) {
+            Ok(seg) => seg,
+            Err(err) => panic!("{}", err)
+        }
+    }
+
+    ///Construct from wkt of a two point linestring, returns an error on invalid input
+    pub fn from_wkt_checked(s: &str) -> Result<Segment, GeomError> {
+        Segment::from_str(s)
+    }
+
     #[inline]
     pub fn a(&self) -> &Point {
         &self.coordinates[0]
@@ -45,11 +65,16 @@ impl Segment {
         return intersection(self.a(), self.b(), other.a(), other.b());
     }
 
-    ///Is segment
+    ///A segment never meets itself
     pub fn is_simple(&self) -> bool {
         true
     }
 
+    ///Area within distance of segment
+    pub fn buffer(&self, distance: f64, params: BufferParams) -> MultiPolygon {
+        buffer::buffer_line(&self.coordinates, distance, &params)
+    }
+
     pub fn wkt(&self) -> String {
         format!("LINESTRING({})", self.coordinates
             .iter()
@@ -78,6 +103,16 @@ impl Geometry for Segment {
         self.wkt()
     }
 
+    fn wkb_bytes(&self, byte_order: ByteOrder) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(41);
+        wkb::write_linestring(&mut buf, byte_order, &self.coordinates);
+        buf
+    }
+
+    fn geojson_value(&self) -> Value {
+        geojson::geometry_value("LineString", geojson::positions_value(&self.coordinates))
+    }
+
     fn geom_type(&self) -> GeomType {
         GeomType::Segment
     }
@@ -95,6 +130,44 @@ impl Geometry for Segment {
     }
 }
 
+impl FromStr for Segment {
+    type Err = GeomError;
+    fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
+        Segment::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
+impl TryFrom<WKT> for Segment {
+    type Error = GeomError;
+    fn try_from(o: WKT) -> Result<Self, Self::Error> {
+        match o.geom_type {
+            GeomType::LineString => {
+                let coordinates = o.coordinates.into_iter().next().unwrap_or_default();
+                match coordinates.len() {
+                    2 => Ok(Segment::new(coordinates[0], coordinates[1])),
+                    n if n < 2 => Err(GeomError::TooFewCoordinates { expected: 2, found: n }),
+                    //a longer linestring is not a segment
+                    _ => Err(GeomError::WrongGeomType {
+                        expected: GeomType::Segment,
+                        found: GeomType::LineString,
+                    })
+                }
+            }
+            _ => Err(GeomError::WrongGeomType {
+                expected: GeomType::Segment,
+                found: o.geom_type,
+            })
+        }
+    }
+}
+
+impl TryFrom<&str> for Segment {
+    type Error = GeomError;
+    fn try_from(wkt_str: &str) -> Result<Self, Self::Error> {
+        Segment::try_from(parse_wkt(wkt_str)?)
+    }
+}
+
 //do two lines intersect line segments a && b with
 //vertices sa, sb, oa, ob
 pub fn intersects(sa: &Point, sb: &Point, oa: &Point, ob: &Point) -> bool {
@@ -0,0 +1,401 @@
+use crate::{Point, LineString, Polygon, Location, MonoMBR, segment};
+use crate::relate::locate_in_ring;
+use bbox_2d::MBR;
+use rtree_2d::{RTree, RTreeObject};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, BTreeMap, BTreeSet};
+
+///Ramer-Douglas-Peucker simplification of an open line
+pub fn douglas_peucker(coords: &[Point], tolerance: f64) -> Vec<Point> {
+    let n = coords.len();
+    if n < 3 {
+        return coords.to_vec();
+    }
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[n - 1] = true;
+    dp_keep(coords, 0, n - 1, tolerance, &mut keep);
+    kept(coords, &keep)
+}
+
+///Ramer-Douglas-Peucker simplification of a closed ring, keeps at least 4 coordinates
+pub fn douglas_peucker_ring(coords: &[Point], tolerance: f64) -> Vec<Point> {
+    let n = coords.len();
+    if n <= 4 {
+        return coords.to_vec();
+    }
+    //first and last coincide : split at the vertex farthest from the first
+    let k = farthest(coords, 1, n - 1, |pt| coords[0].point_distance(pt));
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[k] = true;
+    keep[n - 1] = true;
+    dp_keep(coords, 0, k, tolerance, &mut keep);
+    dp_keep(coords, k, n - 1, tolerance, &mut keep);
+    if keep.iter().filter(|&&v| v).count() < 4 {
+        let (a, b) = (coords[0], coords[k]);
+        let i = farthest(coords, 1, n - 1, |pt| segment::distance_to_point(&a, &b, pt));
+        keep[i] = true;
+    }
+    kept(coords, &keep)
+}
+
+///Visvalingam-Whyatt simplification of an open line, vertices with an
+///effective area below the threshold are removed
+pub fn visvalingam(coords: &[Point], area_threshold: f64) -> Vec<Point> {
+    vw_keep(coords, area_threshold, 2)
+}
+
+///Visvalingam-Whyatt simplification of a closed ring, keeps at least 4 coordinates
+pub fn visvalingam_ring(coords: &[Point], area_threshold: f64) -> Vec<Point> {
+    vw_keep(coords, area_threshold, 4)
+}
+
+///Topology preserving simplification of polygons and lines taken as one set of linework.
+///Linework is split into edges at nodes (vertices shared by more than two segments and line
+///endpoints) ; an edge shared by several rings or lines is simplified once, so adjacent
+///polygons stay adjacent. A vertex run is replaced by a segment only if the segment meets
+///no other edge except at this edge's nodes and no vertex ends up on the other side of it,
+///rings therefore never collapse or self-intersect. Features are expected to share vertices
+///where they meet.
+pub fn simplify_topology(polygons: &[Polygon], lines: &[LineString], tolerance: f64) -> (Vec<Polygon>, Vec<LineString>) {
+    let rings: Vec<Vec<Vec<Point>>> = polygons.iter()
+        .map(|ply| ply.0.iter().map(|r| dedup(r.coordinates())).collect())
+        .collect();
+    let lines: Vec<Vec<Point>> = lines.iter().map(|ln| dedup(&ln.coordinates)).collect();
+
+    let nodes = find_nodes(&rings, &lines);
+    let mut edges = EdgeSet::default();
+    let ring_edges: Vec<Vec<Vec<(usize, bool)>>> = rings.iter()
+        .map(|ply| ply.iter().map(|r| edges.add_ring(r, &nodes)).collect())
+        .collect();
+    let line_edges: Vec<Vec<(usize, bool)>> = lines.iter()
+        .map(|ln| edges.add_line(ln, &nodes))
+        .collect();
+
+    let mut current: Vec<LineString> = edges.coords.into_iter().map(LineString::new).collect();
+    //simplified edges only shrink, their first bounds stay a safe filter
+    let index = RTree::load(current.iter().enumerate()
+        .map(|(e, ln)| MonoMBR { mbr: ln.bounds.mbr, i: e, j: e })
+        .collect());
+    for e in 0..current.len() {
+        simplify_edge(&mut current, &index, e, tolerance);
+    }
+
+    let polygons = ring_edges.iter().zip(rings.iter())
+        .map(|(ply, rs)| Polygon::new(ply.iter().zip(rs.iter()).map(|(r, coords)| {
+            restart(assemble(&current, r), &coords[0])
+        }).collect()))
+        .collect();
+    let lines = line_edges.iter()
+        .map(|ln| LineString::new(assemble(&current, ln)))
+        .collect();
+    (polygons, lines)
+}
+
+fn dedup(coords: &[Point]) -> Vec<Point> {
+    let mut coords = coords.to_vec();
+    coords.dedup_by(|p, q| p.equals(q));
+    coords
+}
+
+//vertices with other than two distinct neighbours, and line endpoints
+fn find_nodes(rings: &[Vec<Vec<Point>>], lines: &[Vec<Point>]) -> BTreeSet<Point> {
+    let mut neighbours: BTreeMap<Point, BTreeSet<Point>> = BTreeMap::new();
+    let mut link = |coords: &[Point]| for w in coords.windows(2) {
+        neighbours.entry(w[0]).or_default().insert(w[1]);
+        neighbours.entry(w[1]).or_default().insert(w[0]);
+    };
+    rings.iter().flatten().for_each(|r| link(r));
+    lines.iter().for_each(|ln| link(ln));
+
+    let mut nodes: BTreeSet<Point> = neighbours.into_iter()
+        .filter(|(_, nbs)| nbs.len() != 2)
+        .map(|(pt, _)| pt)
+        .collect();
+    for ln in lines.iter() {
+        nodes.insert(ln[0]);
+        nodes.insert(ln[ln.len() - 1]);
+    }
+    nodes
+}
+
+//unique edges, keyed by their smallest direction
+#[derive(Default)]
+struct EdgeSet {
+    coords: Vec<Vec<Point>>,
+    keys: BTreeMap<Vec<Point>, usize>,
+}
+
+impl EdgeSet {
+    //edge index and whether the edge is traversed reversed
+    fn add(&mut self, coords: Vec<Point>) -> (usize, bool) {
+        let rev: Vec<Point> = coords.iter().rev().cloned().collect();
+        let reversed = rev < coords;
+        let key = if reversed { rev } else { coords };
+        let n = self.coords.len();
+        let id = *self.keys.entry(key.clone()).or_insert(n);
+        if id == n {
+            self.coords.push(key);
+        }
+        (id, reversed)
+    }
+
+    fn add_line(&mut self, coords: &[Point], nodes: &BTreeSet<Point>) -> Vec<(usize, bool)> {
+        let mut parts = vec![];
+        let mut start = 0;
+        for i in 1..coords.len() {
+            if i == coords.len() - 1 || nodes.contains(&coords[i]) {
+                parts.push(self.add(coords[start..=i].to_vec()));
+                start = i;
+            }
+        }
+        parts
+    }
+
+    fn add_ring(&mut self, coords: &[Point], nodes: &BTreeSet<Point>) -> Vec<(usize, bool)> {
+        let open = &coords[..coords.len() - 1];
+        //start at a node, or at the smallest vertex when the ring has none
+        let start = open.iter().position(|pt| nodes.contains(pt)).unwrap_or_else(|| {
+            (0..open.len()).min_by(|&i, &j| open[i].cmp(&open[j])).unwrap_or(0)
+        });
+        let mut rotated: Vec<Point> = open[start..].iter().chain(open[..start].iter()).cloned().collect();
+        rotated.push(rotated[0]);
+        self.add_line(&rotated, nodes)
+    }
+}
+
+fn assemble(edges: &[LineString], parts: &[(usize, bool)]) -> Vec<Point> {
+    let mut coords: Vec<Point> = vec![];
+    for &(e, reversed) in parts.iter() {
+        let mut pts = edges[e].coordinates.clone();
+        if reversed {
+            pts.reverse();
+        }
+        let skip = if coords.is_empty() { 0 } else { 1 };
+        coords.extend(pts.into_iter().skip(skip));
+    }
+    coords
+}
+
+//rotate ring to start at pt if it survived simplification
+fn restart(mut coords: Vec<Point>, pt: &Point) -> Vec<Point> {
+    if let Some(i) = coords.iter().position(|v| v.equals(pt)) {
+        coords.pop();
+        coords.rotate_left(i);
+        coords.push(coords[0]);
+    }
+    coords
+}
+
+//Douglas-Peucker on edge e, each flattened run validated against the current linework
+fn simplify_edge(edges: &mut [LineString], index: &RTree<MonoMBR>, e: usize, tolerance: f64) {
+    let pts = edges[e].coordinates.clone();
+    let n = pts.len();
+    if n < 3 {
+        return;
+    }
+    let mut keep = vec![true; n];
+    let mut stack = vec![];
+    if pts[0].equals(&pts[n - 1]) {
+        if n <= 4 {
+            return;
+        }
+        let k = farthest(&pts, 1, n - 1, |pt| pts[0].point_distance(pt));
+        stack.push((k, n - 1));
+        stack.push((0, k));
+    } else {
+        stack.push((0, n - 1));
+    }
+    while let Some((i, j)) = stack.pop() {
+        if j <= i + 1 {
+            continue;
+        }
+        let (a, b) = (pts[i], pts[j]);
+        let k = farthest(&pts, i + 1, j, |pt| segment::distance_to_point(&a, &b, pt));
+        if segment::distance_to_point(&a, &b, &pts[k]) <= tolerance && can_flatten(edges, index, e, &pts, &keep, i, j) {
+            keep[i + 1..j].iter_mut().for_each(|v| *v = false);
+        } else {
+            stack.push((k, j));
+            stack.push((i, k));
+        }
+    }
+    //runs of this edge are checked against keep, others only see it once it is done
+    if keep.iter().any(|&k| !k) {
+        edges[e] = LineString::new(kept(&pts, &keep));
+    }
+}
+
+//can vertices i + 1 .. j of edge e be replaced by segment i, j
+fn can_flatten(edges: &[LineString], index: &RTree<MonoMBR>, e: usize, pts: &[Point], keep: &[bool], i: usize, j: usize) -> bool {
+    let (a, b) = (pts[i], pts[j]);
+    let seg = LineString::new(vec![a, b]);
+    //area between the run and the segment
+    let mut swept = pts[i..=j].to_vec();
+    swept.push(a);
+    let sweep = Sweep { bounds: LineString::new(swept.clone()).bounds.mbr, ring: swept };
+
+    //rest of the edge, may touch the segment at its ends
+    let head = kept(&pts[..=i], &keep[..=i]);
+    let tail = kept(&pts[j..], &keep[j..]);
+    for rest in [head, tail].iter().filter(|r| r.len() > 1) {
+        let rest = LineString::new(rest.clone());
+        if !meets_only_at(&seg, &rest, &[a, b]) || sweep.covers_any(&rest.coordinates) {
+            return false;
+        }
+    }
+
+    //other edges near the swept area may only touch at the nodes of this edge
+    let mut nodes = vec![];
+    if i == 0 {
+        nodes.push(a);
+    }
+    if j == pts.len() - 1 {
+        nodes.push(b);
+    }
+    index.search(&MonoMBR::new_mono(sweep.bounds).envelope()).into_iter()
+        .filter(|m| m.i != e)
+        .all(|m| {
+            let other = &edges[m.i];
+            let near: Vec<Point> = other.segments_in(&sweep.bounds).into_iter()
+                .flat_map(|q| vec![other.coordinates[q], other.coordinates[q + 1]])
+                .collect();
+            meets_only_at(&seg, other, &nodes) && !sweep.covers_any(&near)
+        })
+}
+
+//segment meets ln only at allowed points, collinear overlaps are never allowed
+fn meets_only_at(seg: &LineString, ln: &LineString, allowed: &[Point]) -> bool {
+    if !seg.intersects_linestring(ln) {
+        return true;
+    }
+    let (a, b) = (seg.coordinates[0], seg.coordinates[1]);
+    seg.segment_pairs(ln).into_iter().all(|(_, q)| {
+        let pts = segment::intersection(&a, &b, &ln.coordinates[q], &ln.coordinates[q + 1]);
+        pts.len() < 2 && pts.iter().all(|ip| allowed.iter().any(|pt| pt.equals(&ip.pt)))
+    })
+}
+
+struct Sweep {
+    ring: Vec<Point>,
+    bounds: MBR,
+}
+
+impl Sweep {
+    //a vertex strictly between the run and its replacing segment would change sides
+    fn covers_any(&self, coords: &[Point]) -> bool {
+        coords.iter().any(|pt| {
+            self.bounds.intersects_xy(pt.x, pt.y) && locate_in_ring(&self.ring, pt) == Location::Interior
+        })
+    }
+}
+
+fn kept(coords: &[Point], keep: &[bool]) -> Vec<Point> {
+    coords.iter().zip(keep.iter()).filter(|(_, &k)| k).map(|(pt, _)| *pt).collect()
+}
+
+//index in i..j that maximises dist, i if the range is empty
+fn farthest<F: Fn(&Point) -> f64>(coords: &[Point], i: usize, j: usize, dist: F) -> usize {
+    let mut index = i;
+    let mut dmax = -1.0;
+    for (k, pt) in coords.iter().enumerate().take(j).skip(i) {
+        let d = dist(pt);
+        if d > dmax {
+            dmax = d;
+            index = k;
+        }
+    }
+    index
+}
+
+fn dp_keep(coords: &[Point], i: usize, j: usize, tolerance: f64, keep: &mut [bool]) {
+    let mut stack = vec![(i, j)];
+    while let Some((i, j)) = stack.pop() {
+        if j <= i + 1 {
+            continue;
+        }
+        let (a, b) = (coords[i], coords[j]);
+        let k = farthest(coords, i + 1, j, |pt| segment::distance_to_point(&a, &b, pt));
+        if segment::distance_to_point(&a, &b, &coords[k]) > tolerance {
+            keep[k] = true;
+            stack.push((i, k));
+            stack.push((k, j));
+        }
+    }
+}
+
+struct VWVertex {
+    area: f64,
+    index: usize,
+}
+
+impl PartialEq for VWVertex {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area && self.index == other.index
+    }
+}
+
+impl Eq for VWVertex {}
+
+impl PartialOrd for VWVertex {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+//reversed : smallest area on top of the heap
+impl Ord for VWVertex {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.area.partial_cmp(&self.area).unwrap_or(Ordering::Equal)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+fn triangle_area(a: &Point, b: &Point, c: &Point) -> f64 {
+    ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() * 0.5
+}
+
+fn vw_keep(coords: &[Point], area_threshold: f64, min_size: usize) -> Vec<Point> {
+    let n = coords.len();
+    if n <= min_size {
+        return coords.to_vec();
+    }
+    let mut prev: Vec<usize> = (0..n).map(|i| i.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1).min(n - 1)).collect();
+    let mut areas = vec![f64::INFINITY; n];
+    let mut removed = vec![false; n];
+    let mut heap = BinaryHeap::with_capacity(n);
+    for i in 1..n - 1 {
+        areas[i] = triangle_area(&coords[i - 1], &coords[i], &coords[i + 1]);
+        heap.push(VWVertex { area: areas[i], index: i });
+    }
+
+    let mut size = n;
+    while let Some(v) = heap.pop() {
+        let i = v.index;
+        //stale entry, vertex area changed since it was queued
+        if removed[i] || v.area != areas[i] {
+            continue;
+        }
+        if v.area >= area_threshold || size <= min_size {
+            break;
+        }
+        removed[i] = true;
+        size -= 1;
+        let (p, q) = (prev[i], next[i]);
+        next[p] = q;
+        prev[q] = p;
+        for &j in [p, q].iter() {
+            if j == 0 || j == n - 1 {
+                continue;
+            }
+            //effective area never drops below that of the removed vertex
+            areas[j] = triangle_area(&coords[prev[j]], &coords[j], &coords[next[j]]).max(v.area);
+            heap.push(VWVertex { area: areas[j], index: j });
+        }
+    }
+    coords.iter().zip(removed.iter()).filter(|(_, &r)| !r).map(|(pt, _)| *pt).collect()
+}
+
+#[cfg(test)]
+mod tests;
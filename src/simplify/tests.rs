@@ -0,0 +1,144 @@
+use super::*;
+use crate::{LineString, LinearRing, Polygon, Geometry, Location};
+
+#[test]
+fn test_douglas_peucker() {
+    let ln = LineString::from_wkt("LINESTRING(0 0,1 0.1,2 -0.1,3 5,4 6,5 7,6 8.1,7 9,8 9,9 9)");
+    assert_eq!(ln.simplify(0.5).wkt(), "LINESTRING(0 0,2 -0.1,3 5,7 9,9 9)");
+    //exactly collinear vertices are dropped even at zero tolerance
+    assert_eq!(ln.simplify(0.).coordinates.len(), 8);
+    assert_eq!(ln.simplify(100.).wkt(), "LINESTRING(0 0,9 9)");
+    let seg = LineString::from_wkt("LINESTRING(0 0,9 9)");
+    assert_eq!(seg.simplify(1.).wkt(), "LINESTRING(0 0,9 9)");
+}
+
+#[test]
+fn test_visvalingam() {
+    let ln = LineString::from_wkt("LINESTRING(0 0,1 0.1,2 -0.1,3 5,4 6,5 7,6 8.1,7 9,8 9,9 9)");
+    assert_eq!(ln.simplify_vw(0.5).wkt(), "LINESTRING(0 0,2 -0.1,3 5,7 9,9 9)");
+    assert_eq!(ln.simplify_vw(0.).coordinates.len(), 10);
+    assert_eq!(ln.simplify_vw(1000.).wkt(), "LINESTRING(0 0,9 9)");
+}
+
+#[test]
+fn test_simplify_rings() {
+    let coords: Vec<Point> = (0..64).map(|i| {
+        let a = (i as f64) * std::f64::consts::PI * 2.0 / 64.0;
+        Point::new(10.0 * a.cos(), 10.0 * a.sin())
+    }).collect();
+    let ring = LinearRing::new(coords);
+    for r in [ring.simplify(0.5), ring.simplify(1000.), ring.simplify_vw(1.), ring.simplify_vw(1e6)].iter() {
+        let c = r.coordinates();
+        assert!(c.len() >= 4 && c.len() < 65);
+        assert!(c[0].equals(&c[c.len() - 1]));
+    }
+    assert_eq!(ring.simplify(1000.).coordinates().len(), 4);
+    assert_eq!(ring.simplify_vw(1e6).coordinates().len(), 4);
+
+    let ply = Polygon::from_wkt(
+        "POLYGON((0 0,5 0.1,10 0,10 5,10.1 10,5 10,0 10,0 0),(4 4,4 5,4 6,5 6,6 6,6 4,4 4))"
+    );
+    let s = ply.simplify(0.5);
+    assert_eq!(s.wkt(), "POLYGON((0 0,10 0,10.1 10,0 10,0 0),(4 4,4 6,6 6,6 4,4 4))");
+    let s = ply.simplify_vw(1.);
+    assert_eq!(s.wkt(), "POLYGON((0 0,10 0,10.1 10,0 10,0 0),(4 4,4 6,6 6,6 4,4 4))");
+    assert!(ply.simplify(1000.).holes()[0].coordinates().len() >= 4);
+    assert!(ply.simplify_vw(1e6).area() > 0.);
+}
+
+#[test]
+fn test_simplify_topology_shared_edge() {
+    //two parcels sharing a wiggly boundary x ~ 5
+    let a = Polygon::from_wkt("POLYGON((0 0,5 0,5.2 2,4.8 4,5.2 6,4.8 8,5 10,0 10,0 0))");
+    let b = Polygon::from_wkt("POLYGON((5 0,10 0,10 10,5 10,4.8 8,5.2 6,4.8 4,5.2 2,5 0))");
+    let (plys, lns) = simplify_topology(&[a.clone(), b.clone()], &[], 1.);
+    assert!(lns.is_empty());
+    assert_eq!(plys[0].wkt(), "POLYGON((0 0,5 0,5 10,0 10,0 0))");
+    assert_eq!(plys[1].wkt(), "POLYGON((5 0,10 0,10 10,5 10,5 0))");
+    //no sliver or overlap : areas still add up
+    let total = plys[0].area() + plys[1].area();
+    assert!((total - (a.area() + b.area())).abs() < 1e-9);
+}
+
+#[test]
+fn test_simplify_topology_no_new_intersections() {
+    //plain simplification swallows one line and crosses the other
+    let ply = Polygon::from_wkt("POLYGON((0 0,5 3,10 0,10 10,0 10,0 0))");
+    let l1 = LineString::from_wkt("LINESTRING(4 1,6 1)");
+    let l2 = LineString::from_wkt("LINESTRING(5 -1,5 1)");
+    let plain = ply.simplify(5.);
+    assert_eq!(plain.locate_point(&l1.coordinates[0]), Location::Interior);
+    assert!(plain.shell().line_string().intersects_linestring(&l2));
+
+    let (plys, lns) = simplify_topology(&[ply], &[l1.clone(), l2.clone()], 5.);
+    let shell = plys[0].shell();
+    assert_eq!(plys[0].locate_point(&l1.coordinates[0]), Location::Exterior);
+    assert!(!shell.line_string().intersects_linestring(&lns[0]));
+    assert!(!shell.line_string().intersects_linestring(&lns[1]));
+    assert_eq!(lns[0].wkt(), l1.wkt());
+    assert_eq!(lns[1].wkt(), l2.wkt());
+    assert!(shell.coordinates().len() >= 4);
+
+    //hole stays inside the shell
+    let ply = Polygon::from_wkt("POLYGON((0 0,5 -3,10 0,10 10,0 10,0 0),(4 -1,6 -1,5 -2,4 -1))");
+    let plain = ply.simplify(5.);
+    assert_eq!(plain.shell().locate_point(&Point::new(5., -2.)), Location::Exterior);
+    let (plys, _) = simplify_topology(&[ply], &[], 5.);
+    let p = &plys[0];
+    assert_eq!(p.holes().len(), 1);
+    for pt in p.holes()[0].coordinates().iter() {
+        assert_eq!(p.shell().locate_point(pt), Location::Interior);
+    }
+}
+
+#[test]
+fn test_simplify_topology_rings_and_lines() {
+    //a ring with no nodes keeps at least a triangle
+    let ply = Polygon::from_wkt("POLYGON((0 0,1 0.1,2 0,2 1,1 1.1,0 1,0 0))");
+    let (plys, _) = simplify_topology(&[ply], &[], 100.);
+    let coords = plys[0].shell().coordinates();
+    assert_eq!(coords.len(), 4);
+    assert!(plys[0].area() > 0.);
+
+    //two lines crossing at a shared vertex keep the crossing, endpoints are kept
+    let l1 = LineString::from_wkt("LINESTRING(0 0,1 0.1,2 0,3 0.1,4 0)");
+    let l2 = LineString::from_wkt("LINESTRING(2 -2,2.1 -1,2 0,2.1 1,2 2)");
+    let (_, lns) = simplify_topology(&[], &[l1, l2], 0.5);
+    assert_eq!(lns[0].wkt(), "LINESTRING(0 0,2 0,4 0)");
+    assert_eq!(lns[1].wkt(), "LINESTRING(2 -2,2 0,2 2)");
+}
+
+#[test]
+fn test_simplify_topology_parcel_grid() {
+    //grid of parcels with wiggly shared sides, every side simplified once
+    let wiggle = |a: Point, b: Point| -> Vec<Point> {
+        (0..=10).map(|k| {
+            let t = k as f64 / 10.;
+            let off = if k % 2 == 1 { 0.05 } else { 0. };
+            Point::new(a.x + t * (b.x - a.x) + off, a.y + t * (b.y - a.y) + off)
+        }).collect()
+    };
+    let mut plys = vec![];
+    for i in 0..6 {
+        for j in 0..6 {
+            let (x, y) = (i as f64 * 10., j as f64 * 10.);
+            let corners = [Point::new(x, y), Point::new(x + 10., y), Point::new(x + 10., y + 10.), Point::new(x, y + 10.)];
+            let mut coords: Vec<Point> = vec![];
+            for k in 0..4 {
+                let (a, b) = (corners[k], corners[(k + 1) % 4]);
+                //shared sides are digitised the same way from both parcels
+                let side = if a < b { wiggle(a, b) } else { wiggle(b, a).into_iter().rev().collect() };
+                coords.extend(side.into_iter().skip(if coords.is_empty() { 0 } else { 1 }));
+            }
+            plys.push(Polygon::from_vec(&coords));
+        }
+    }
+    let (out, _) = simplify_topology(&plys, &[], 0.5);
+    assert_eq!(out.len(), 36);
+    for (p, q) in out.iter().zip(plys.iter()) {
+        assert!(p.is_valid());
+        assert_eq!(p.shell().coordinates().len(), 5);
+        assert!((p.area() - q.area()).abs() < 1.);
+    }
+    assert!((out.iter().map(|p| p.area()).sum::<f64>() - 3600.).abs() < 1e-9);
+}
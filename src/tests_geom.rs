@@ -4,6 +4,7 @@ use crate::{
     Point,  pts,
     Polygon, LineString, ln,
     GeomType, Geometry, convex_hull,
+    GeomError, parse_wkt,
 };
 use crate::distance::{min_dist_brute_force, knn_min_linear_distance};
 use crate::inter::OTHER_A;
@@ -39,13 +40,120 @@ fn test_construct_3() {
 #[test]
 #[should_panic]
 fn test_construct_4() {
-    let _ply: Polygon = "LINESTRING(5.6 7.9,5.6 8.9,6.6 8.9,6.6 7.9,5.6 7.9)".into();
+    let _ply = Polygon::from_wkt("LINESTRING(5.6 7.9,5.6 8.9,6.6 8.9,6.6 7.9,5.6 7.9)");
 }
 
 #[test]
 #[should_panic]
 fn test_construct_5() {
-    let _ply: Polygon = "Polygon(5.6 7.9,5.6 8.9,6.6 8.9,6.6 7.9,5.6 7.9)".into();
+    let _ply = Polygon::from_wkt("Polygon(5.6 7.9,5.6 8.9,6.6 8.9,6.6 7.9,5.6 7.9)");
+}
+
+#[test]
+fn test_wkt_checked() {
+    let pt = Point::from_wkt_checked("POINT(3 4)").unwrap();
+    assert_eq!(pt, Point::new(3., 4.));
+    let ln = LineString::from_wkt_checked("LINESTRING(5.6 7.9,5.6 8.9)").unwrap();
+    assert_eq!(ln.coordinates.len(), 2);
+    let ply = Polygon::from_wkt_checked("POLYGON((0 0,0 1,1 1,1 0,0 0))").unwrap();
+    assert_eq!(ply.area(), 1.0);
+    let ply: Result<Polygon, GeomError> = "POLYGON((0 0,0 1,1 1,1 0,0 0))".parse();
+    assert!(ply.is_ok());
+
+    match Point::from_wkt_checked("POINT(3 4") {
+        Err(GeomError::Parse { position, .. }) => assert_eq!(position, 9),
+        _ => unreachable!(),
+    }
+    match Point::from_wkt_checked("POINT(3 4) x") {
+        Err(GeomError::Parse { position, .. }) => assert_eq!(position, 11),
+        _ => unreachable!(),
+    }
+    assert_eq!(
+        Point::from_wkt_checked("LINESTRING(5.6 7.9,5.6 8.9)").unwrap_err(),
+        GeomError::WrongGeomType { expected: GeomType::Point, found: GeomType::LineString }
+    );
+    assert_eq!(
+        Polygon::from_wkt_checked("POINT(3 4)").unwrap_err(),
+        GeomError::WrongGeomType { expected: GeomType::Polygon, found: GeomType::Point }
+    );
+    assert_eq!(
+        LineString::from_wkt_checked("LINESTRING(5.6 7.9)").unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 2, found: 1 }
+    );
+    assert_eq!(
+        Point::from_wkt_checked("POINT EMPTY").unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 1, found: 0 }
+    );
+    assert_eq!(
+        Polygon::from_wkt_checked("POLYGON((0 0,0 1,1 0))").unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 4, found: 3 }
+    );
+    assert_eq!(
+        Polygon::from_wkt_checked("POLYGON((0 0,0 4,4 4,4 0,0 0),(1 1,1 2,2 2,2 1))").unwrap_err(),
+        GeomError::UnclosedRing { ring: 1 }
+    );
+    assert_eq!(
+        LineString::from_wkt_checked("MULTIPOINT((3 4),(5 6))").unwrap_err(),
+        GeomError::WrongGeomType { expected: GeomType::LineString, found: GeomType::MultiPoint }
+    );
+    let err = Polygon::from_wkt_checked("GEOMETRYCOLLECTION(POINT(3 4))").unwrap_err();
+    assert_eq!(format!("{}", err), "invalid geometry type, expected Polygon, got : GeometryCollection");
+    assert!(parse_wkt("GEOMETRYCOLLECTION(POINT(3 4))").is_ok());
+
+    let ring = LinearRing::from_wkt_checked("LINESTRING(0 0,0 1,1 1,0 0)").unwrap();
+    assert_eq!(ring.coordinates().len(), 4);
+    assert_eq!(
+        LinearRing::from_wkt_checked("LINESTRING(0 0,0 1,1 1,1 0)").unwrap_err(),
+        GeomError::UnclosedRing { ring: 0 }
+    );
+    assert_eq!(
+        LinearRing::from_wkt_checked("LINESTRING(0 0,0 1,0 0)").unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 4, found: 3 }
+    );
+    assert_eq!(
+        LinearRing::from_wkt_checked("POLYGON((0 0,0 1,1 1,0 0))").unwrap_err(),
+        GeomError::WrongGeomType { expected: GeomType::LineString, found: GeomType::Polygon }
+    );
+    let seg = Segment::from_wkt_checked("LINESTRING(0 0,3 4)").unwrap();
+    assert_eq!((*seg.a(), *seg.b()), (Point::new(0., 0.), Point::new(3., 4.)));
+    assert_eq!(
+        Segment::from_wkt_checked("LINESTRING(0 0)").unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 2, found: 1 }
+    );
+    assert_eq!(
+        Segment::from_wkt_checked("LINESTRING(0 0,3 4,5 5)").unwrap_err(),
+        GeomError::WrongGeomType { expected: GeomType::Segment, found: GeomType::LineString }
+    );
+    assert_eq!(
+        Segment::from_wkt_checked("POINT(3 4)").unwrap_err(),
+        GeomError::WrongGeomType { expected: GeomType::Segment, found: GeomType::Point }
+    );
+    assert!(Segment::from_wkt_checked("LINESTRING(0 0,3 4").is_err());
+
+    //try_from a string is fallible, never a panic
+    use std::convert::TryFrom;
+    assert!(matches!(Point::try_from("bad"), Err(GeomError::Parse { position: 0, .. })));
+    assert!(LineString::try_from("LINESTRING(0 0)").is_err());
+    assert!(Polygon::try_from("POLYGON((0 0,0 1,1 1,1 0))").is_err());
+    assert_eq!(Point::try_from("POINT(3 4)").unwrap(), Point::new(3., 4.));
+    let wkt = crate::wkt::WKT { geom_type: GeomType::Point, coordinates: vec![], geometries: vec![] };
+    assert_eq!(Point::try_from(wkt).unwrap_err(), GeomError::TooFewCoordinates { expected: 1, found: 0 });
+
+    //errors are reported where parsing stops
+    let position = |s: &str| match parse_wkt(s) {
+        Err(GeomError::Parse { position, .. }) => position,
+        _ => unreachable!(),
+    };
+    assert_eq!(position(""), 0);
+    assert_eq!(position("POINT(3 x)"), 8);
+    assert_eq!(position("LINESTRING(0 0;1 1)"), 14);
+    assert_eq!(position("POLYGON((0 0,0 1,1 1,0 0)"), 25);
+    assert_eq!(position("GEOMETRYCOLLECTION(POINT(1 1),CIRCLE(1 1))"), 30);
+    assert_eq!(position("  POINT (3 4)  ,"), 15);
+
+    assert_eq!(Point::from_wkt_checked("point z (3 4 5)").unwrap(), Point::new(3., 4.));
+    let mpt = parse_wkt("MULTIPOINT(1 2,(3 4))").unwrap();
+    assert_eq!(mpt.coordinates, vec![vec![Point::new(1., 2.), Point::new(3., 4.)]]);
 }
 
 #[test]
@@ -67,7 +175,7 @@ fn test_wkt_construct() {
     let ln = ln![[5.6, 7.9], [5.6, 8.9], [6.6, 8.9], [6.6, 7.9], [5.6, 7.9]];
     let ln_str = "LINESTRING(5.6 7.9,5.6 8.9,6.6 8.9,6.6 7.9,5.6 7.9)".to_string();
     assert_eq!(ln.wkt(), ln_str);
-    let ln_2: LineString = ln_str.as_str().into();
+    let ln_2 = LineString::from_wkt(&ln_str);
     assert_eq!(ln.wkt(), ln_2.wkt());
 }
 
@@ -442,17 +550,17 @@ fn test_geom_distance<T:Geometry>(a:&T, b:&T) -> f64{
 }
 #[test]
 fn test_distance() {
-    let poly_a:Polygon = "POLYGON (( 450 600, 450 725, 575 725, 575 600, 450 600 ))".into();
-    let poly_b:Polygon = "POLYGON (( 675 475, 675 550, 825 550, 825 475, 675 475 ))".into();
-    let poly_c:Polygon = "POLYGON (( 575 650, 575 775, 650 775, 650 650, 575 650 ))".into();
+    let poly_a = Polygon::from_wkt("POLYGON (( 450 600, 450 725, 575 725, 575 600, 450 600 ))");
+    let poly_b = Polygon::from_wkt("POLYGON (( 675 475, 675 550, 825 550, 825 475, 675 475 ))");
+    let poly_c = Polygon::from_wkt("POLYGON (( 575 650, 575 775, 650 775, 650 650, 575 650 ))");
     assert_eq!(round(poly_a.distance(&poly_b), 1), 111.8);
     assert_eq!(round(test_geom_distance(&poly_a, &poly_b), 1), 111.8);
     assert_eq!(round(test_geom_distance(&poly_a, &poly_c), 1), 0.);
 
     let wkt_a = "LINESTRING ( 201.4094393973535 156.03490222759163, 204.4094393973535 157.03490222759163, 205.4094393973535 156.03490222759163, 206.4094393973535 153.03490222759163, 206.4094393973535 152.03490222759163, 208.4094393973535 153.03490222759163, 211.4094393973535 153.03490222759163, 212.4094393973535 152.03490222759163, 215.4094393973535 151.03490222759163, 216.4094393973535 151.03490222759163, 217.4094393973535 151.03490222759163, 218.4094393973535 151.03490222759163, 221.4094393973535 152.03490222759163, 223.4094393973535 151.03490222759163, 224.4094393973535 151.03490222759163, 224.4094393973535 154.03490222759163, 224.4094393973535 157.03490222759163, 225.4094393973535 158.03490222759163, 225.4094393973535 161.03490222759163, 226.4094393973535 161.03490222759163, 228.4094393973535 163.03490222759163, 228.4094393973535 165.03490222759163, 229.4094393973535 165.03490222759163, 230.4094393973535 168.03490222759163, 229.4094393973535 165.03490222759163, 229.4094393973535 164.03490222759163, 230.4094393973535 164.03490222759163, 230.4094393973535 165.03490222759163, 232.4094393973535 167.03490222759163, 230.4094393973535 169.03490222759163, 227.4094393973535 170.03490222759163, 226.4094393973535 172.03490222759163, 226.4094393973535 173.03490222759163, 227.4094393973535 172.03490222759163, 229.4094393973535 170.03490222759163, 232.4094393973535 170.03490222759163, 233.4094393973535 170.03490222759163, 234.4094393973535 172.03490222759163, 235.4094393973535 174.03490222759163, 235.4094393973535 176.03490222759163, 236.4094393973535 178.03490222759163, 235.4094393973535 180.03490222759163, 235.4094393973535 181.03490222759163, 235.4094393973535 182.03490222759163, 234.4094393973535 182.03490222759163, 233.4094393973535 180.03490222759163, 234.4094393973535 179.03490222759163, 234.4094393973535 176.03490222759163, 233.4094393973535 176.03490222759163, 232.4094393973535 174.03490222759163, 230.4094393973535 174.03490222759163, 230.4094393973535 176.03490222759163, 232.4094393973535 176.03490222759163, 230.4094393973535 176.03490222759163, 232.4094393973535 178.03490222759163, 232.4094393973535 180.03490222759163, 229.4094393973535 182.03490222759163, 233.4094393973535 181.03490222759163, 233.4094393973535 182.03490222759163, 234.4094393973535 183.03490222759163, 233.4094393973535 184.03490222759163, 230.4094393973535 185.03490222759163, 229.4094393973535 184.03490222759163, 226.4094393973535 186.03490222759163, 226.4094393973535 189.03490222759163, 226.4094393973535 191.03490222759163, 223.4094393973535 193.03490222759163, 222.4094393973535 193.03490222759163, 222.4094393973535 192.03490222759163, 221.4094393973535 191.03490222759163, 219.4094393973535 191.03490222759163, 218.4094393973535 191.03490222759163, 219.4094393973535 191.03490222759163, 221.4094393973535 193.03490222759163, 222.4094393973535 193.03490222759163, 219.4094393973535 194.03490222759163, 217.4094393973535 193.03490222759163, 216.4094393973535 193.03490222759163, 216.4094393973535 194.03490222759163, 216.4094393973535 196.03490222759163, 216.4094393973535 197.03490222759163, 218.4094393973535 197.03490222759163, 218.4094393973535 200.03490222759163, 218.4094393973535 201.03490222759163, 218.4094393973535 202.03490222759163, 218.4094393973535 204.03490222759166, 218.4094393973535 205.03490222759166, 222.4094393973535 208.0349022275916, 221.4094393973535 209.0349022275916, 219.4094393973535 213.0349022275916, 221.4094393973535 214.0349022275916, 222.4094393973535 214.0349022275916, 222.4094393973535 215.0349022275916, 219.4094393973535 214.0349022275916, 219.4094393973535 215.0349022275916, 218.4094393973535 215.0349022275916, 219.4094393973535 217.0349022275916, 221.4094393973535 218.0349022275916, 221.4094393973535 220.0349022275916, 222.4094393973535 224.0349022275916, 221.4094393973535 228.0349022275916, 223.4094393973535 228.0349022275916, 221.4094393973535 233.0349022275916, 219.4094393973535 234.0349022275916, 218.4094393973535 236.0349022275916, 217.4094393973535 239.0349022275916, 217.4094393973535 242.0349022275916, 214.4094393973535 248.0349022275916, 213.4094393973535 249.0349022275916, 212.4094393973535 249.0349022275916, 212.4094393973535 250.0349022275916, 213.4094393973535 251.0349022275916, 213.4094393973535 250.0349022275916, 213.4094393973535 252.0349022275916, 214.4094393973535 253.0349022275916, 213.4094393973535 256.0349022275916, 212.4094393973535 256.0349022275916, 213.4094393973535 255.0349022275916, 211.4094393973535 256.0349022275916, 211.4094393973535 255.0349022275916, 208.4094393973535 256.0349022275916, 206.4094393973535 253.0349022275916, 204.4094393973535 255.0349022275916, 205.4094393973535 253.0349022275916, 204.4094393973535 253.0349022275916, 203.4094393973535 253.0349022275916, 204.4094393973535 255.0349022275916, 203.4094393973535 255.0349022275916, 202.4094393973535 256.0349022275916, 201.4094393973535 257.0349022275916, 202.4094393973535 256.0349022275916, 202.4094393973535 255.0349022275916, 202.4094393973535 253.0349022275916, 201.4094393973535 253.0349022275916, 201.4094393973535 251.0349022275916, 201.4094393973535 253.0349022275916, 201.4094393973535 255.0349022275916, 200.4094393973535 257.0349022275916, 197.4094393973535 256.0349022275916, 199.4094393973535 255.0349022275916, 197.4094393973535 255.0349022275916, 197.4094393973535 256.0349022275916, 196.4094393973535 256.0349022275916, 195.4094393973535 256.0349022275916, 191.4094393973535 256.0349022275916, 189.4094393973535 257.0349022275916, 188.4094393973535 257.0349022275916, 186.4094393973535 257.0349022275916, 188.4094393973535 258.0349022275916, 189.4094393973535 259.0349022275916, 188.4094393973535 259.0349022275916, 188.4094393973535 260.0349022275916, 184.4094393973535 260.0349022275916, 184.4094393973535 262.0349022275916, 183.4094393973535 262.0349022275916, 182.4094393973535 262.0349022275916, 181.4094393973535 261.0349022275916, 181.4094393973535 262.0349022275916, 180.4094393973535 262.0349022275916, 181.4094393973535 263.0349022275916, 179.4094393973535 264.0349022275916, 178.4094393973535 266.0349022275916, 174.4094393973535 267.0349022275916, 173.4094393973535 266.0349022275916, 174.4094393973535 264.0349022275916, 175.4094393973535 263.0349022275916, 172.4094393973535 264.0349022275916, 170.4094393973535 263.0349022275916, 170.4094393973535 264.0349022275916, 171.4094393973535 264.0349022275916, 172.4094393973535 266.0349022275916, 172.4094393973535 267.0349022275916, 170.4094393973535 269.0349022275916, 169.4094393973535 269.0349022275916, 168.4094393973535 269.0349022275916, 167.4094393973535 269.0349022275916, 166.4094393973535 270.0349022275916, 166.4094393973535 272.0349022275916, 166.4094393973535 271.0349022275916, 163.4094393973535 271.0349022275916, 162.4094393973535 274.0349022275916, 161.4094393973535 274.0349022275916, 161.4094393973535 272.0349022275916, 159.4094393973535 272.0349022275916, 159.4094393973535 274.0349022275916, 158.4094393973535 274.0349022275916, 156.4094393973535 272.0349022275916, 155.4094393973535 274.0349022275916, 153.4094393973535 272.0349022275916, 153.4094393973535 274.0349022275916, 152.4094393973535 274.0349022275916, 152.4094393973535 273.0349022275916, 152.4094393973535 275.0349022275916, 151.4094393973535 275.0349022275916, 150.4094393973535 274.0349022275916, 150.4094393973535 275.0349022275916, 149.4094393973535 275.0349022275916, 148.4094393973535 274.0349022275916, 149.4094393973535 272.0349022275916, 148.4094393973535 271.0349022275916, 148.4094393973535 272.0349022275916, 147.4094393973535 272.0349022275916, 146.4094393973535 272.0349022275916, 146.4094393973535 274.0349022275916, 145.4094393973535 274.0349022275916, 144.4094393973535 274.0349022275916, 144.4094393973535 272.0349022275916, 141.4094393973535 274.0349022275916, 142.4094393973535 274.0349022275916, 140.4094393973535 274.0349022275916, 140.4094393973535 273.0349022275916, 139.4094393973535 274.0349022275916, 139.4094393973535 273.0349022275916, 141.4094393973535 271.0349022275916, 144.4094393973535 271.0349022275916, 146.4094393973535 269.0349022275916, 140.4094393973535 271.0349022275916, 139.4094393973535 271.0349022275916, 140.4094393973535 270.0349022275916, 141.4094393973535 269.0349022275916, 148.4094393973535 268.0349022275916, 148.4094393973535 267.0349022275916, 147.4094393973535 266.0349022275916, 147.4094393973535 264.0349022275916, 147.4094393973535 266.0349022275916, 146.4094393973535 268.0349022275916, 138.4094393973535 268.0349022275916, 138.4094393973535 269.0349022275916, 136.4094393973535 269.0349022275916, 135.4094393973535 269.0349022275916, 134.4094393973535 269.0349022275916, 134.4094393973535 268.0349022275916, 135.4094393973535 269.0349022275916, 135.4094393973535 268.0349022275916, 135.4094393973535 267.0349022275916, 137.4094393973535 267.0349022275916, 138.4094393973535 266.0349022275916, 137.4094393973535 266.0349022275916, 137.4094393973535 264.0349022275916, 139.4094393973535 263.0349022275916, 139.4094393973535 264.0349022275916, 139.4094393973535 263.0349022275916, 141.4094393973535 263.0349022275916, 140.4094393973535 263.0349022275916, 142.4094393973535 261.0349022275916, 145.4094393973535 261.0349022275916, 146.4094393973535 261.0349022275916, 144.4094393973535 261.0349022275916, 141.4094393973535 262.0349022275916, 140.4094393973535 262.0349022275916, 139.4094393973535 262.0349022275916, 134.4094393973535 264.0349022275916, 134.4094393973535 263.0349022275916, 133.4094393973535 263.0349022275916, 133.4094393973535 262.0349022275916, 134.4094393973535 261.0349022275916, 133.4094393973535 260.0349022275916, 131.4094393973535 261.0349022275916, 130.4094393973535 262.0349022275916, 130.4094393973535 260.0349022275916, 129.4094393973535 260.0349022275916, 129.4094393973535 258.0349022275916, 131.4094393973535 258.0349022275916, 133.4094393973535 258.0349022275916, 131.4094393973535 257.0349022275916, 133.4094393973535 256.0349022275916, 138.4094393973535 253.0349022275916, 139.4094393973535 252.0349022275916, 139.4094393973535 253.0349022275916, 140.4094393973535 251.0349022275916, 141.4094393973535 251.0349022275916, 144.4094393973535 251.0349022275916, 140.4094393973535 250.0349022275916, 139.4094393973535 250.0349022275916, 139.4094393973535 251.0349022275916, 138.4094393973535 250.0349022275916, 135.4094393973535 251.0349022275916, 134.4094393973535 250.0349022275916, 133.4094393973535 250.0349022275916, 131.4094393973535 250.0349022275916, 133.4094393973535 251.0349022275916, 130.4094393973535 250.0349022275916, 131.4094393973535 251.0349022275916, 129.4094393973535 251.0349022275916, 128.4094393973535 250.0349022275916, 128.4094393973535 249.0349022275916, 129.4094393973535 249.0349022275916, 128.4094393973535 248.0349022275916, 129.4094393973535 248.0349022275916, 129.4094393973535 249.0349022275916, 130.4094393973535 249.0349022275916, 130.4094393973535 247.0349022275916, 131.4094393973535 247.0349022275916, 133.4094393973535 246.0349022275916, 135.4094393973535 246.0349022275916, 135.4094393973535 247.0349022275916, 137.4094393973535 248.0349022275916, 138.4094393973535 246.0349022275916, 137.4094393973535 245.0349022275916, 138.4094393973535 244.0349022275916, 138.4094393973535 246.0349022275916, 139.4094393973535 248.0349022275916, 141.4094393973535 248.0349022275916, 144.4094393973535 248.0349022275916, 144.4094393973535 247.0349022275916, 140.4094393973535 247.0349022275916, 140.4094393973535 245.0349022275916, 141.4094393973535 246.0349022275916, 141.4094393973535 245.0349022275916, 142.4094393973535 242.0349022275916, 140.4094393973535 241.0349022275916, 145.4094393973535 240.0349022275916, 146.4094393973535 239.0349022275916, 147.4094393973535 240.0349022275916, 147.4094393973535 239.0349022275916, 146.4094393973535 239.0349022275916, 147.4094393973535 237.0349022275916, 148.4094393973535 237.0349022275916, 150.4094393973535 238.0349022275916, 150.4094393973535 237.0349022275916, 153.4094393973535 238.0349022275916, 159.4094393973535 236.0349022275916, 159.4094393973535 237.0349022275916, 160.4094393973535 237.0349022275916, 161.4094393973535 236.0349022275916, 163.4094393973535 236.0349022275916, 166.4094393973535 236.0349022275916, 162.4094393973535 235.0349022275916, 161.4094393973535 235.0349022275916, 161.4094393973535 234.0349022275916, 160.4094393973535 233.0349022275916, 159.4094393973535 233.0349022275916, 159.4094393973535 234.0349022275916, 156.4094393973535 237.0349022275916, 155.4094393973535 236.0349022275916, 153.4094393973535 238.0349022275916, 152.4094393973535 237.0349022275916, 153.4094393973535 236.0349022275916, 151.4094393973535 236.0349022275916, 149.4094393973535 236.0349022275916, 149.4094393973535 235.0349022275916, 148.4094393973535 234.0349022275916, 149.4094393973535 236.0349022275916, 146.4094393973535 236.0349022275916, 146.4094393973535 237.0349022275916, 141.4094393973535 236.0349022275916, 140.4094393973535 236.0349022275916, 144.4094393973535 236.0349022275916, 147.4094393973535 233.0349022275916, 148.4094393973535 231.0349022275916, 150.4094393973535 231.0349022275916, 150.4094393973535 229.0349022275916, 151.4094393973535 228.0349022275916, 151.4094393973535 226.0349022275916, 153.4094393973535 225.0349022275916, 152.4094393973535 225.0349022275916, 150.4094393973535 225.0349022275916, 151.4094393973535 224.0349022275916, 155.4094393973535 219.0349022275916, 158.4094393973535 219.0349022275916, 159.4094393973535 219.0349022275916, 161.4094393973535 219.0349022275916, 159.4094393973535 219.0349022275916, 161.4094393973535 219.0349022275916, 162.4094393973535 219.0349022275916, 162.4094393973535 218.0349022275916, 163.4094393973535 217.0349022275916, 162.4094393973535 217.0349022275916, 161.4094393973535 217.0349022275916, 161.4094393973535 216.0349022275916, 162.4094393973535 215.0349022275916, 162.4094393973535 214.0349022275916, 160.4094393973535 214.0349022275916, 158.4094393973535 215.0349022275916, 151.4094393973535 215.0349022275916, 150.4094393973535 215.0349022275916, 150.4094393973535 213.0349022275916, 149.4094393973535 215.0349022275916, 149.4094393973535 214.0349022275916, 149.4094393973535 213.0349022275916, 149.4094393973535 212.0349022275916, 150.4094393973535 212.0349022275916, 149.4094393973535 211.0349022275916, 149.4094393973535 212.0349022275916, 149.4094393973535 211.0349022275916, 148.4094393973535 212.0349022275916, 147.4094393973535 212.0349022275916, 146.4094393973535 211.0349022275916, 145.4094393973535 211.0349022275916, 145.4094393973535 209.0349022275916, 147.4094393973535 208.0349022275916, 145.4094393973535 208.0349022275916, 144.4094393973535 208.0349022275916, 144.4094393973535 209.0349022275916, 141.4094393973535 208.0349022275916, 140.4094393973535 207.03490222759166, 139.4094393973535 208.0349022275916, 139.4094393973535 207.03490222759166, 138.4094393973535 207.03490222759166, 139.4094393973535 205.03490222759166, 141.4094393973535 206.03490222759166, 140.4094393973535 205.03490222759166, 140.4094393973535 204.03490222759166, 139.4094393973535 203.03490222759163, 138.4094393973535 203.03490222759163, 140.4094393973535 203.03490222759163, 140.4094393973535 202.03490222759163, 144.4094393973535 203.03490222759163, 141.4094393973535 200.03490222759163, 146.4094393973535 200.03490222759163, 146.4094393973535 198.03490222759163, 146.4094393973535 196.03490222759163, 148.4094393973535 195.03490222759163, 150.4094393973535 196.03490222759163, 151.4094393973535 195.03490222759163, 150.4094393973535 195.03490222759163, 151.4094393973535 194.03490222759163, 150.4094393973535 194.03490222759163, 151.4094393973535 193.03490222759163, 151.4094393973535 192.03490222759163, 148.4094393973535 192.03490222759163, 147.4094393973535 192.03490222759163, 146.4094393973535 192.03490222759163, 146.4094393973535 190.03490222759163, 148.4094393973535 191.03490222759163, 148.4094393973535 189.03490222759163, 148.4094393973535 190.03490222759163, 148.4094393973535 189.03490222759163, 147.4094393973535 186.03490222759163, 147.4094393973535 185.03490222759163, 148.4094393973535 185.03490222759163, 147.4094393973535 185.03490222759163, 148.4094393973535 184.03490222759163, 147.4094393973535 184.03490222759163, 147.4094393973535 185.03490222759163, 146.4094393973535 184.03490222759163, 147.4094393973535 183.03490222759163, 146.4094393973535 183.03490222759163, 147.4094393973535 182.03490222759163, 146.4094393973535 182.03490222759163, 147.4094393973535 181.03490222759163, 145.4094393973535 180.03490222759163, 145.4094393973535 181.03490222759163, 145.4094393973535 182.03490222759163, 144.4094393973535 182.03490222759163, 144.4094393973535 183.03490222759163, 144.4094393973535 184.03490222759163, 142.4094393973535 184.03490222759163, 144.4094393973535 180.03490222759163, 142.4094393973535 179.03490222759163, 144.4094393973535 179.03490222759163, 146.4094393973535 176.03490222759163, 146.4094393973535 179.03490222759163, 147.4094393973535 180.03490222759163, 147.4094393973535 181.03490222759163, 146.4094393973535 180.03490222759163, 148.4094393973535 181.03490222759163, 148.4094393973535 179.03490222759163, 148.4094393973535 180.03490222759163, 148.4094393973535 179.03490222759163, 148.4094393973535 178.03490222759163, 148.4094393973535 176.03490222759163, 150.4094393973535 178.03490222759163, 155.4094393973535 178.03490222759163, 156.4094393973535 180.03490222759163, 158.4094393973535 179.03490222759163, 159.4094393973535 179.03490222759163, 159.4094393973535 180.03490222759163, 160.4094393973535 181.03490222759163, 160.4094393973535 182.03490222759163, 161.4094393973535 183.03490222759163, 163.4094393973535 180.03490222759163, 166.4094393973535 180.03490222759163, 168.4094393973535 182.03490222759163, 169.4094393973535 181.03490222759163, 170.4094393973535 182.03490222759163, 172.4094393973535 183.03490222759163, 173.4094393973535 183.03490222759163, 172.4094393973535 182.03490222759163, 173.4094393973535 181.03490222759163, 174.4094393973535 181.03490222759163, 173.4094393973535 180.03490222759163, 174.4094393973535 180.03490222759163, 173.4094393973535 179.03490222759163, 172.4094393973535 180.03490222759163, 171.4094393973535 179.03490222759163, 173.4094393973535 178.03490222759163, 173.4094393973535 176.03490222759163, 175.4094393973535 176.03490222759163, 174.4094393973535 175.03490222759163, 175.4094393973535 174.03490222759163, 175.4094393973535 175.03490222759163, 179.4094393973535 175.03490222759163, 180.4094393973535 173.03490222759163, 180.4094393973535 172.03490222759163, 182.4094393973535 170.03490222759163, 183.4094393973535 170.03490222759163, 182.4094393973535 170.03490222759163, 180.4094393973535 170.03490222759163, 179.4094393973535 170.03490222759163, 178.4094393973535 170.03490222759163, 175.4094393973535 172.03490222759163, 175.4094393973535 170.03490222759163, 178.4094393973535 170.03490222759163, 175.4094393973535 170.03490222759163, 173.4094393973535 170.03490222759163, 172.4094393973535 170.03490222759163, 169.4094393973535 168.03490222759163, 170.4094393973535 167.03490222759163, 169.4094393973535 167.03490222759163, 170.4094393973535 167.03490222759163, 170.4094393973535 165.03490222759163, 172.4094393973535 164.03490222759163, 177.4094393973535 165.03490222759163, 175.4094393973535 164.03490222759163, 174.4094393973535 164.03490222759163, 175.4094393973535 164.03490222759163, 178.4094393973535 165.03490222759163, 174.4094393973535 162.03490222759163, 175.4094393973535 162.03490222759163, 179.4094393973535 162.03490222759163, 178.4094393973535 161.03490222759163, 179.4094393973535 161.03490222759163, 180.4094393973535 161.03490222759163, 179.4094393973535 161.03490222759163, 178.4094393973535 160.03490222759163, 178.4094393973535 161.03490222759163, 177.4094393973535 160.03490222759163, 177.4094393973535 158.03490222759163, 179.4094393973535 158.03490222759163, 178.4094393973535 158.03490222759163, 177.4094393973535 156.03490222759163, 178.4094393973535 156.03490222759163, 178.4094393973535 154.03490222759163, 179.4094393973535 156.03490222759163, 179.4094393973535 154.03490222759163, 180.4094393973535 154.03490222759163, 180.4094393973535 153.03490222759163, 181.4094393973535 152.03490222759163, 183.4094393973535 152.03490222759163, 183.4094393973535 153.03490222759163, 184.4094393973535 152.03490222759163, 186.4094393973535 152.03490222759163, 186.4094393973535 150.03490222759163, 186.4094393973535 149.03490222759163, 188.4094393973535 150.03490222759163, 188.4094393973535 151.03490222759163, 189.4094393973535 151.03490222759163, 189.4094393973535 152.03490222759163, 190.4094393973535 152.03490222759163, 189.4094393973535 152.03490222759163, 190.4094393973535 152.03490222759163, 190.4094393973535 151.03490222759163, 189.4094393973535 151.03490222759163, 189.4094393973535 149.03490222759163, 190.4094393973535 149.03490222759163, 190.4094393973535 152.03490222759163, 191.4094393973535 152.03490222759163, 192.4094393973535 153.03490222759163, 192.4094393973535 154.03490222759163, 193.4094393973535 153.03490222759163, 192.4094393973535 150.03490222759163, 192.4094393973535 152.03490222759163, 192.4094393973535 151.03490222759163, 192.4094393973535 150.03490222759163, 191.4094393973535 150.03490222759163, 191.4094393973535 149.03490222759163, 192.4094393973535 149.03490222759163, 193.4094393973535 148.03490222759163, 194.4094393973535 149.03490222759163, 194.4094393973535 151.03490222759163, 195.4094393973535 153.03490222759163, 195.4094393973535 154.03490222759163, 193.4094393973535 157.03490222759163, 194.4094393973535 157.03490222759163, 193.4094393973535 157.03490222759163, 192.4094393973535 159.03490222759163, 194.4094393973535 157.03490222759163, 196.4094393973535 156.03490222759163, 196.4094393973535 153.03490222759163, 195.4094393973535 150.03490222759163, 196.4094393973535 150.03490222759163, 195.4094393973535 149.03490222759163, 196.4094393973535 149.03490222759163, 199.4094393973535 148.03490222759163, 199.4094393973535 149.03490222759163, 201.4094393973535 149.03490222759163, 199.4094393973535 148.03490222759163, 199.4094393973535 147.03490222759163, 199.4094393973535 146.03490222759163, 197.4094393973535 146.03490222759163, 201.4094393973535 146.03490222759163, 202.4094393973535 147.03490222759163, 203.4094393973535 148.03490222759163, 207.4094393973535 150.03490222759163, 207.4094393973535 151.03490222759163, 203.4094393973535 153.03490222759163 )";
     let wkt_b = "LINESTRING ( 92.08384332442225 204.3026301378301, 95.08384332442225 205.3026301378301, 96.08384332442225 204.3026301378301, 97.08384332442225 201.3026301378301, 97.08384332442225 200.3026301378301, 99.08384332442225 201.3026301378301, 102.08384332442225 201.3026301378301, 103.08384332442225 200.3026301378301, 106.08384332442225 199.3026301378301, 107.08384332442225 199.3026301378301, 108.08384332442225 199.3026301378301, 109.08384332442225 199.3026301378301, 112.08384332442225 200.3026301378301, 114.08384332442225 199.3026301378301, 115.08384332442225 199.3026301378301, 115.08384332442225 202.3026301378301, 115.08384332442225 205.3026301378301, 116.08384332442225 206.3026301378301, 116.08384332442225 209.3026301378301, 117.08384332442225 209.3026301378301, 119.08384332442225 211.3026301378301, 119.08384332442225 213.3026301378301, 120.08384332442225 213.3026301378301, 121.08384332442225 216.3026301378301, 120.08384332442225 213.3026301378301, 120.08384332442225 212.3026301378301, 121.08384332442225 212.3026301378301, 121.08384332442225 213.3026301378301, 123.08384332442225 215.3026301378301, 121.08384332442225 217.3026301378301, 118.08384332442225 218.3026301378301, 117.08384332442225 220.3026301378301, 117.08384332442225 221.3026301378301, 118.08384332442225 220.3026301378301, 120.08384332442225 218.3026301378301, 123.08384332442225 218.3026301378301, 124.08384332442225 218.3026301378301, 125.08384332442225 220.3026301378301, 126.08384332442225 222.3026301378301, 126.08384332442225 224.3026301378301, 127.08384332442225 226.3026301378301, 126.08384332442225 228.3026301378301, 126.08384332442225 229.3026301378301, 126.08384332442225 230.3026301378301, 125.08384332442225 230.3026301378301, 124.08384332442225 228.3026301378301, 125.08384332442225 227.3026301378301, 125.08384332442225 224.3026301378301, 124.08384332442225 224.3026301378301, 123.08384332442225 222.3026301378301, 121.08384332442225 222.3026301378301, 121.08384332442225 224.3026301378301, 123.08384332442225 224.3026301378301, 121.08384332442225 224.3026301378301, 123.08384332442225 226.3026301378301, 123.08384332442225 228.3026301378301, 120.08384332442225 230.3026301378301, 124.08384332442225 229.3026301378301, 124.08384332442225 230.3026301378301, 125.08384332442225 231.3026301378301, 124.08384332442225 232.3026301378301, 121.08384332442225 233.3026301378301, 120.08384332442225 232.3026301378301, 117.08384332442225 234.3026301378301, 117.08384332442225 237.3026301378301, 117.08384332442225 239.3026301378301, 114.08384332442225 241.3026301378301, 113.08384332442225 241.3026301378301, 113.08384332442225 240.3026301378301, 112.08384332442225 239.3026301378301, 110.08384332442225 239.3026301378301, 109.08384332442225 239.3026301378301, 110.08384332442225 239.3026301378301, 112.08384332442225 241.3026301378301, 113.08384332442225 241.3026301378301, 110.08384332442225 242.3026301378301, 108.08384332442225 241.3026301378301, 107.08384332442225 241.3026301378301, 107.08384332442225 242.3026301378301, 107.08384332442225 244.3026301378301, 107.08384332442225 245.3026301378301, 109.08384332442225 245.3026301378301, 109.08384332442225 248.3026301378301, 109.08384332442225 249.3026301378301, 109.08384332442225 250.3026301378301, 109.08384332442225 252.3026301378301, 109.08384332442225 253.3026301378301, 113.08384332442225 256.3026301378301, 112.08384332442225 257.3026301378301, 110.08384332442225 261.3026301378301, 112.08384332442225 262.3026301378301, 113.08384332442225 262.3026301378301, 113.08384332442225 263.3026301378301, 110.08384332442225 262.3026301378301, 110.08384332442225 263.3026301378301, 109.08384332442225 263.3026301378301, 110.08384332442225 265.3026301378301, 112.08384332442225 266.3026301378301, 112.08384332442225 268.3026301378301, 113.08384332442225 272.3026301378301, 112.08384332442225 276.3026301378301, 114.08384332442225 276.3026301378301, 112.08384332442225 281.3026301378301, 110.08384332442225 282.3026301378301, 109.08384332442225 284.3026301378301, 108.08384332442225 287.3026301378301, 108.08384332442225 290.3026301378301, 105.08384332442225 296.3026301378301, 104.08384332442225 297.3026301378301, 103.08384332442225 297.3026301378301, 103.08384332442225 298.3026301378301, 104.08384332442225 299.3026301378301, 104.08384332442225 298.3026301378301, 104.08384332442225 300.3026301378301, 105.08384332442225 301.3026301378301, 104.08384332442225 304.3026301378301, 103.08384332442225 304.3026301378301, 104.08384332442225 303.3026301378301, 102.08384332442225 304.3026301378301, 102.08384332442225 303.3026301378301, 99.08384332442225 304.3026301378301, 97.08384332442225 301.3026301378301, 95.08384332442225 303.3026301378301, 96.08384332442225 301.3026301378301, 95.08384332442225 301.3026301378301, 94.08384332442225 301.3026301378301, 95.08384332442225 303.3026301378301, 94.08384332442225 303.3026301378301, 93.08384332442225 304.3026301378301, 92.08384332442225 305.3026301378301, 93.08384332442225 304.3026301378301, 93.08384332442225 303.3026301378301, 93.08384332442225 301.3026301378301, 92.08384332442225 301.3026301378301, 92.08384332442225 299.3026301378301, 92.08384332442225 301.3026301378301, 92.08384332442225 303.3026301378301, 91.08384332442225 305.3026301378301, 88.08384332442225 304.3026301378301, 90.08384332442225 303.3026301378301, 88.08384332442225 303.3026301378301, 88.08384332442225 304.3026301378301, 87.08384332442225 304.3026301378301, 86.08384332442225 304.3026301378301, 82.08384332442225 304.3026301378301, 80.08384332442225 305.3026301378301, 79.08384332442225 305.3026301378301, 77.08384332442225 305.3026301378301, 79.08384332442225 306.3026301378301, 80.08384332442225 307.3026301378301, 79.08384332442225 307.3026301378301, 79.08384332442225 308.3026301378301, 75.08384332442225 308.3026301378301, 75.08384332442225 310.3026301378301, 74.08384332442225 310.3026301378301, 73.08384332442225 310.3026301378301, 72.08384332442225 309.3026301378301, 72.08384332442225 310.3026301378301, 71.08384332442225 310.3026301378301, 72.08384332442225 311.3026301378301, 70.08384332442225 312.3026301378301, 69.08384332442225 314.3026301378301, 65.08384332442225 315.3026301378301, 64.08384332442225 314.3026301378301, 65.08384332442225 312.3026301378301, 66.08384332442225 311.3026301378301, 63.08384332442225 312.3026301378301, 61.08384332442225 311.3026301378301, 61.08384332442225 312.3026301378301, 62.08384332442225 312.3026301378301, 63.08384332442225 314.3026301378301, 63.08384332442225 315.3026301378301, 61.08384332442225 317.3026301378301, 60.08384332442225 317.3026301378301, 59.08384332442225 317.3026301378301, 58.08384332442225 317.3026301378301, 57.08384332442225 318.3026301378301, 57.08384332442225 320.3026301378301, 57.08384332442225 319.3026301378301, 54.08384332442225 319.3026301378301, 53.08384332442225 322.3026301378301, 52.08384332442225 322.3026301378301, 52.08384332442225 320.3026301378301, 50.08384332442225 320.3026301378301, 50.08384332442225 322.3026301378301, 49.08384332442225 322.3026301378301, 47.08384332442225 320.3026301378301, 46.08384332442225 322.3026301378301, 44.08384332442225 320.3026301378301, 44.08384332442225 322.3026301378301, 43.08384332442225 322.3026301378301, 43.08384332442225 321.3026301378301, 43.08384332442225 323.3026301378301, 42.08384332442225 323.3026301378301, 41.08384332442225 322.3026301378301, 41.08384332442225 323.3026301378301, 40.08384332442225 323.3026301378301, 39.08384332442225 322.3026301378301, 40.08384332442225 320.3026301378301, 39.08384332442225 319.3026301378301, 39.08384332442225 320.3026301378301, 38.08384332442225 320.3026301378301, 37.08384332442225 320.3026301378301, 37.08384332442225 322.3026301378301, 36.08384332442225 322.3026301378301, 35.08384332442225 322.3026301378301, 35.08384332442225 320.3026301378301, 32.08384332442225 322.3026301378301, 33.08384332442225 322.3026301378301, 31.083843324422247 322.3026301378301, 31.083843324422247 321.3026301378301, 30.083843324422247 322.3026301378301, 30.083843324422247 321.3026301378301, 32.08384332442225 319.3026301378301, 35.08384332442225 319.3026301378301, 37.08384332442225 317.3026301378301, 31.083843324422247 319.3026301378301, 30.083843324422247 319.3026301378301, 31.083843324422247 318.3026301378301, 32.08384332442225 317.3026301378301, 39.08384332442225 316.3026301378301, 39.08384332442225 315.3026301378301, 38.08384332442225 314.3026301378301, 38.08384332442225 312.3026301378301, 38.08384332442225 314.3026301378301, 37.08384332442225 316.3026301378301, 29.083843324422247 316.3026301378301, 29.083843324422247 317.3026301378301, 27.083843324422247 317.3026301378301, 26.083843324422247 317.3026301378301, 25.083843324422247 317.3026301378301, 25.083843324422247 316.3026301378301, 26.083843324422247 317.3026301378301, 26.083843324422247 316.3026301378301, 26.083843324422247 315.3026301378301, 28.083843324422247 315.3026301378301, 29.083843324422247 314.3026301378301, 28.083843324422247 314.3026301378301, 28.083843324422247 312.3026301378301, 30.083843324422247 311.3026301378301, 30.083843324422247 312.3026301378301, 30.083843324422247 311.3026301378301, 32.08384332442225 311.3026301378301, 31.083843324422247 311.3026301378301, 33.08384332442225 309.3026301378301, 36.08384332442225 309.3026301378301, 37.08384332442225 309.3026301378301, 35.08384332442225 309.3026301378301, 32.08384332442225 310.3026301378301, 31.083843324422247 310.3026301378301, 30.083843324422247 310.3026301378301, 25.083843324422247 312.3026301378301, 25.083843324422247 311.3026301378301, 24.083843324422247 311.3026301378301, 24.083843324422247 310.3026301378301, 25.083843324422247 309.3026301378301, 24.083843324422247 308.3026301378301, 22.083843324422247 309.3026301378301, 21.083843324422247 310.3026301378301, 21.083843324422247 308.3026301378301, 20.083843324422247 308.3026301378301, 20.083843324422247 306.3026301378301, 22.083843324422247 306.3026301378301, 24.083843324422247 306.3026301378301, 22.083843324422247 305.3026301378301, 24.083843324422247 304.3026301378301, 29.083843324422247 301.3026301378301, 30.083843324422247 300.3026301378301, 30.083843324422247 301.3026301378301, 31.083843324422247 299.3026301378301, 32.08384332442225 299.3026301378301, 35.08384332442225 299.3026301378301, 31.083843324422247 298.3026301378301, 30.083843324422247 298.3026301378301, 30.083843324422247 299.3026301378301, 29.083843324422247 298.3026301378301, 26.083843324422247 299.3026301378301, 25.083843324422247 298.3026301378301, 24.083843324422247 298.3026301378301, 22.083843324422247 298.3026301378301, 24.083843324422247 299.3026301378301, 21.083843324422247 298.3026301378301, 22.083843324422247 299.3026301378301, 20.083843324422247 299.3026301378301, 19.083843324422247 298.3026301378301, 19.083843324422247 297.3026301378301, 20.083843324422247 297.3026301378301, 19.083843324422247 296.3026301378301, 20.083843324422247 296.3026301378301, 20.083843324422247 297.3026301378301, 21.083843324422247 297.3026301378301, 21.083843324422247 295.3026301378301, 22.083843324422247 295.3026301378301, 24.083843324422247 294.3026301378301, 26.083843324422247 294.3026301378301, 26.083843324422247 295.3026301378301, 28.083843324422247 296.3026301378301, 29.083843324422247 294.3026301378301, 28.083843324422247 293.3026301378301, 29.083843324422247 292.3026301378301, 29.083843324422247 294.3026301378301, 30.083843324422247 296.3026301378301, 32.08384332442225 296.3026301378301, 35.08384332442225 296.3026301378301, 35.08384332442225 295.3026301378301, 31.083843324422247 295.3026301378301, 31.083843324422247 293.3026301378301, 32.08384332442225 294.3026301378301, 32.08384332442225 293.3026301378301, 33.08384332442225 290.3026301378301, 31.083843324422247 289.3026301378301, 36.08384332442225 288.3026301378301, 37.08384332442225 287.3026301378301, 38.08384332442225 288.3026301378301, 38.08384332442225 287.3026301378301, 37.08384332442225 287.3026301378301, 38.08384332442225 285.3026301378301, 39.08384332442225 285.3026301378301, 41.08384332442225 286.3026301378301, 41.08384332442225 285.3026301378301, 44.08384332442225 286.3026301378301, 50.08384332442225 284.3026301378301, 50.08384332442225 285.3026301378301, 51.08384332442225 285.3026301378301, 52.08384332442225 284.3026301378301, 54.08384332442225 284.3026301378301, 57.08384332442225 284.3026301378301, 53.08384332442225 283.3026301378301, 52.08384332442225 283.3026301378301, 52.08384332442225 282.3026301378301, 51.08384332442225 281.3026301378301, 50.08384332442225 281.3026301378301, 50.08384332442225 282.3026301378301, 47.08384332442225 285.3026301378301, 46.08384332442225 284.3026301378301, 44.08384332442225 286.3026301378301, 43.08384332442225 285.3026301378301, 44.08384332442225 284.3026301378301, 42.08384332442225 284.3026301378301, 40.08384332442225 284.3026301378301, 40.08384332442225 283.3026301378301, 39.08384332442225 282.3026301378301, 40.08384332442225 284.3026301378301, 37.08384332442225 284.3026301378301, 37.08384332442225 285.3026301378301, 32.08384332442225 284.3026301378301, 31.083843324422247 284.3026301378301, 35.08384332442225 284.3026301378301, 38.08384332442225 281.3026301378301, 39.08384332442225 279.3026301378301, 41.08384332442225 279.3026301378301, 41.08384332442225 277.3026301378301, 42.08384332442225 276.3026301378301, 42.08384332442225 274.3026301378301, 44.08384332442225 273.3026301378301, 43.08384332442225 273.3026301378301, 41.08384332442225 273.3026301378301, 42.08384332442225 272.3026301378301, 46.08384332442225 267.3026301378301, 49.08384332442225 267.3026301378301, 50.08384332442225 267.3026301378301, 52.08384332442225 267.3026301378301, 50.08384332442225 267.3026301378301, 52.08384332442225 267.3026301378301, 53.08384332442225 267.3026301378301, 53.08384332442225 266.3026301378301, 54.08384332442225 265.3026301378301, 53.08384332442225 265.3026301378301, 52.08384332442225 265.3026301378301, 52.08384332442225 264.3026301378301, 53.08384332442225 263.3026301378301, 53.08384332442225 262.3026301378301, 51.08384332442225 262.3026301378301, 49.08384332442225 263.3026301378301, 42.08384332442225 263.3026301378301, 41.08384332442225 263.3026301378301, 41.08384332442225 261.3026301378301, 40.08384332442225 263.3026301378301, 40.08384332442225 262.3026301378301, 40.08384332442225 261.3026301378301, 40.08384332442225 260.3026301378301, 41.08384332442225 260.3026301378301, 40.08384332442225 259.3026301378301, 40.08384332442225 260.3026301378301, 40.08384332442225 259.3026301378301, 39.08384332442225 260.3026301378301, 38.08384332442225 260.3026301378301, 37.08384332442225 259.3026301378301, 36.08384332442225 259.3026301378301, 36.08384332442225 257.3026301378301, 38.08384332442225 256.3026301378301, 36.08384332442225 256.3026301378301, 35.08384332442225 256.3026301378301, 35.08384332442225 257.3026301378301, 32.08384332442225 256.3026301378301, 31.083843324422247 255.3026301378301, 30.083843324422247 256.3026301378301, 30.083843324422247 255.3026301378301, 29.083843324422247 255.3026301378301, 30.083843324422247 253.3026301378301, 32.08384332442225 254.3026301378301, 31.083843324422247 253.3026301378301, 31.083843324422247 252.3026301378301, 30.083843324422247 251.3026301378301, 29.083843324422247 251.3026301378301, 31.083843324422247 251.3026301378301, 31.083843324422247 250.3026301378301, 35.08384332442225 251.3026301378301, 32.08384332442225 248.3026301378301, 37.08384332442225 248.3026301378301, 37.08384332442225 246.3026301378301, 37.08384332442225 244.3026301378301, 39.08384332442225 243.3026301378301, 41.08384332442225 244.3026301378301, 42.08384332442225 243.3026301378301, 41.08384332442225 243.3026301378301, 42.08384332442225 242.3026301378301, 41.08384332442225 242.3026301378301, 42.08384332442225 241.3026301378301, 42.08384332442225 240.3026301378301, 39.08384332442225 240.3026301378301, 38.08384332442225 240.3026301378301, 37.08384332442225 240.3026301378301, 37.08384332442225 238.3026301378301, 39.08384332442225 239.3026301378301, 39.08384332442225 237.3026301378301, 39.08384332442225 238.3026301378301, 39.08384332442225 237.3026301378301, 38.08384332442225 234.3026301378301, 38.08384332442225 233.3026301378301, 39.08384332442225 233.3026301378301, 38.08384332442225 233.3026301378301, 39.08384332442225 232.3026301378301, 38.08384332442225 232.3026301378301, 38.08384332442225 233.3026301378301, 37.08384332442225 232.3026301378301, 38.08384332442225 231.3026301378301, 37.08384332442225 231.3026301378301, 38.08384332442225 230.3026301378301, 37.08384332442225 230.3026301378301, 38.08384332442225 229.3026301378301, 36.08384332442225 228.3026301378301, 36.08384332442225 229.3026301378301, 36.08384332442225 230.3026301378301, 35.08384332442225 230.3026301378301, 35.08384332442225 231.3026301378301, 35.08384332442225 232.3026301378301, 33.08384332442225 232.3026301378301, 35.08384332442225 228.3026301378301, 33.08384332442225 227.3026301378301, 35.08384332442225 227.3026301378301, 37.08384332442225 224.3026301378301, 37.08384332442225 227.3026301378301, 38.08384332442225 228.3026301378301, 38.08384332442225 229.3026301378301, 37.08384332442225 228.3026301378301, 39.08384332442225 229.3026301378301, 39.08384332442225 227.3026301378301, 39.08384332442225 228.3026301378301, 39.08384332442225 227.3026301378301, 39.08384332442225 226.3026301378301, 39.08384332442225 224.3026301378301, 41.08384332442225 226.3026301378301, 46.08384332442225 226.3026301378301, 47.08384332442225 228.3026301378301, 49.08384332442225 227.3026301378301, 50.08384332442225 227.3026301378301, 50.08384332442225 228.3026301378301, 51.08384332442225 229.3026301378301, 51.08384332442225 230.3026301378301, 52.08384332442225 231.3026301378301, 54.08384332442225 228.3026301378301, 57.08384332442225 228.3026301378301, 59.08384332442225 230.3026301378301, 60.08384332442225 229.3026301378301, 61.08384332442225 230.3026301378301, 63.08384332442225 231.3026301378301, 64.08384332442225 231.3026301378301, 63.08384332442225 230.3026301378301, 64.08384332442225 229.3026301378301, 65.08384332442225 229.3026301378301, 64.08384332442225 228.3026301378301, 65.08384332442225 228.3026301378301, 64.08384332442225 227.3026301378301, 63.08384332442225 228.3026301378301, 62.08384332442225 227.3026301378301, 64.08384332442225 226.3026301378301, 64.08384332442225 224.3026301378301, 66.08384332442225 224.3026301378301, 65.08384332442225 223.3026301378301, 66.08384332442225 222.3026301378301, 66.08384332442225 223.3026301378301, 70.08384332442225 223.3026301378301, 71.08384332442225 221.3026301378301, 71.08384332442225 220.3026301378301, 73.08384332442225 218.3026301378301, 74.08384332442225 218.3026301378301, 73.08384332442225 218.3026301378301, 71.08384332442225 218.3026301378301, 70.08384332442225 218.3026301378301, 69.08384332442225 218.3026301378301, 66.08384332442225 220.3026301378301, 66.08384332442225 218.3026301378301, 69.08384332442225 218.3026301378301, 66.08384332442225 218.3026301378301, 64.08384332442225 218.3026301378301, 63.08384332442225 218.3026301378301, 60.08384332442225 216.3026301378301, 61.08384332442225 215.3026301378301, 60.08384332442225 215.3026301378301, 61.08384332442225 215.3026301378301, 61.08384332442225 213.3026301378301, 63.08384332442225 212.3026301378301, 68.08384332442225 213.3026301378301, 66.08384332442225 212.3026301378301, 65.08384332442225 212.3026301378301, 66.08384332442225 212.3026301378301, 69.08384332442225 213.3026301378301, 65.08384332442225 210.3026301378301, 66.08384332442225 210.3026301378301, 70.08384332442225 210.3026301378301, 69.08384332442225 209.3026301378301, 70.08384332442225 209.3026301378301, 71.08384332442225 209.3026301378301, 70.08384332442225 209.3026301378301, 69.08384332442225 208.3026301378301, 69.08384332442225 209.3026301378301, 68.08384332442225 208.3026301378301, 68.08384332442225 206.3026301378301, 70.08384332442225 206.3026301378301, 69.08384332442225 206.3026301378301, 68.08384332442225 204.3026301378301, 69.08384332442225 204.3026301378301, 69.08384332442225 202.3026301378301, 70.08384332442225 204.3026301378301, 70.08384332442225 202.3026301378301, 71.08384332442225 202.3026301378301, 71.08384332442225 201.3026301378301, 72.08384332442225 200.3026301378301, 74.08384332442225 200.3026301378301, 74.08384332442225 201.3026301378301, 75.08384332442225 200.3026301378301, 77.08384332442225 200.3026301378301, 77.08384332442225 198.3026301378301, 77.08384332442225 197.3026301378301, 79.08384332442225 198.3026301378301, 79.08384332442225 199.3026301378301, 80.08384332442225 199.3026301378301, 80.08384332442225 200.3026301378301, 81.08384332442225 200.3026301378301, 80.08384332442225 200.3026301378301, 81.08384332442225 200.3026301378301, 81.08384332442225 199.3026301378301, 80.08384332442225 199.3026301378301, 80.08384332442225 197.3026301378301, 81.08384332442225 197.3026301378301, 81.08384332442225 200.3026301378301, 82.08384332442225 200.3026301378301, 83.08384332442225 201.3026301378301, 83.08384332442225 202.3026301378301, 84.08384332442225 201.3026301378301, 83.08384332442225 198.3026301378301, 83.08384332442225 200.3026301378301, 83.08384332442225 199.3026301378301, 83.08384332442225 198.3026301378301, 82.08384332442225 198.3026301378301, 82.08384332442225 197.3026301378301, 83.08384332442225 197.3026301378301, 84.08384332442225 196.3026301378301, 85.08384332442225 197.3026301378301, 85.08384332442225 199.3026301378301, 86.08384332442225 201.3026301378301, 86.08384332442225 202.3026301378301, 84.08384332442225 205.3026301378301, 85.08384332442225 205.3026301378301, 84.08384332442225 205.3026301378301, 83.08384332442225 207.3026301378301, 85.08384332442225 205.3026301378301, 87.08384332442225 204.3026301378301, 87.08384332442225 201.3026301378301, 86.08384332442225 198.3026301378301, 87.08384332442225 198.3026301378301, 86.08384332442225 197.3026301378301, 87.08384332442225 197.3026301378301, 90.08384332442225 196.3026301378301, 90.08384332442225 197.3026301378301, 92.08384332442225 197.3026301378301, 90.08384332442225 196.3026301378301, 90.08384332442225 195.3026301378301, 90.08384332442225 194.3026301378301, 88.08384332442225 194.3026301378301, 92.08384332442225 194.3026301378301, 93.08384332442225 195.3026301378301, 94.08384332442225 196.3026301378301, 98.08384332442225 198.3026301378301, 98.08384332442225 199.3026301378301, 94.08384332442225 201.3026301378301 )";
-    let a: LineString = wkt_a.into();
-    let b: LineString = wkt_b.into();
+    let a = LineString::from_wkt(wkt_a);
+    let b = LineString::from_wkt(wkt_b);
     let expects = min_dist_brute_force(&a, &b);
     let actual = knn_min_linear_distance(&a.coordinates, &b.coordinates);
     assert_eq!(expects, actual);
@@ -481,8 +589,8 @@ fn test_distance() {
 
     let wkt_b = "LINESTRING ( 190.5152458489664 281.16775426125224, 182.87934122063538 276.175047388882, 188.75311401165925 269.42020867920456, 193.45213224447832 269.12652003965337, 193.7458208840295 263.8401245277319, 188.16573673255687 259.1411062949128, 183.17302986018657 261.7843040508735, 177.88663434826512 255.9105312598497, 184.93516169749373 248.8620039106211, 193.7458208840295 252.0925789456842 )";
     let wkt_a = "LINESTRING ( 184.05409577884015 283.2235747381106, 176.41819115050916 278.8182451448427, 176.41819115050916 273.23816099337006, 180.52983210422585 270.59496323740933, 181.9982753019818 268.2454541209998, 180.82352074377704 265.60225636503907, 175.2434365923044 264.1338131672831, 171.1317956385877 260.02217221356636, 172.30655019679247 255.9105312598497, 176.71187979006035 250.33044710837706, 179.94245482512346 243.86929703825083, 188.75311401165925 242.10716520094365 )";
-    let ag: LineString = wkt_a.into();
-    let bg: LineString = wkt_b.into();
+    let ag = LineString::from_wkt(wkt_a);
+    let bg = LineString::from_wkt(wkt_b);
     let expects = ag.distance(&bg);
     let actual = knn_min_linear_distance(&ag.coordinates, &bg.coordinates);
     assert_eq!(expects, actual);
@@ -493,7 +601,7 @@ fn test_distance() {
 #[test]
 fn test_to_segment_intersection() {
     let fn_segment = |wkt: &str| {
-        let ln: LineString = wkt.into();
+        let ln = LineString::from_wkt(wkt);
         segment::Segment::new(ln.coordinates[0], ln.coordinates[1])
     };
 
@@ -592,4 +700,437 @@ fn test_to_segment_intersection() {
 
     assert_eq!(intpts[0].to_string(), "[400, 350, 0010]".to_string());
     assert_eq!(intpts[1].to_string(), "[450, 350, 0101]".to_string());
-}
\ No newline at end of file
+}
+#[test]
+fn test_multi_geometries() {
+    let mpt = MultiPoint::from_wkt("MULTIPOINT((3 4),(10 10))");
+    let mln = MultiLineString::from_wkt("MULTILINESTRING((0 0,5 5),(10 0,10 5))");
+    let mply = MultiPolygon::from_wkt(
+        "MULTIPOLYGON(((0 0,0 10,10 10,10 0,0 0),(2 2,2 8,8 8,8 2,2 2)),((20 0,20 5,25 5,25 0,20 0)))"
+    );
+    assert!(mpt.geom_type().is_multi_point());
+    assert!(mln.geom_type().is_multi_line_string());
+    assert!(mply.geom_type().is_multi_polygon());
+    assert_eq!(format!("{}", mply.geom_type()), "MultiPolygon");
+
+    assert_eq!(mpt.wkt(), "MULTIPOINT((3 4),(10 10))");
+    assert_eq!(mln.wkt(), "MULTILINESTRING((0 0,5 5),(10 0,10 5))");
+    assert_eq!(mply.wkt_string(),
+               "MULTIPOLYGON(((0 0,0 10,10 10,10 0,0 0),(2 2,2 8,8 8,8 2,2 2)),((20 0,20 5,25 5,25 0,20 0)))");
+    assert_eq!(MultiPoint::from_wkt("MULTIPOINT(3 4,10 10)").wkt(), mpt.wkt());
+
+    assert!(mpt.bbox().equals(&MBR::new_from_bounds([3., 4.], [10., 10.])));
+    assert!(mln.bbox().equals(&MBR::new_from_bounds([0., 0.], [10., 5.])));
+    assert!(mply.bbox().equals(&MBR::new_from_bounds([0., 0.], [25., 10.])));
+    assert_eq!(mply.area(), 100. - 36. + 25.);
+    assert_eq!(mply.as_linear().len(), 3);
+    assert_eq!(mln.length(), 50f64.sqrt() + 5.);
+
+    let pt_hole = Point::new(5., 5.);
+    let pt_in = Point::new(22., 2.);
+    let pt_out = Point::new(15., 2.);
+    assert!(!mply.intersects(&pt_hole));
+    assert!(!pt_hole.intersects(&mply));
+    assert!(mply.intersects(&pt_in));
+    assert!(pt_in.intersects(&mply));
+    assert!(!mply.intersects(&pt_out));
+    assert_eq!(mply.distance(&pt_out), 5.);
+    assert_eq!(pt_out.distance(&mply), 5.);
+    assert_eq!(pt_hole.distance(&mply), 3.);
+
+    assert!(mpt.intersects(&mply));
+    assert!(mply.intersects(&mpt));
+    assert!(mln.intersects(&mply));
+    assert!(mply.intersects(&mln));
+    let ln = LineString::from_wkt("LINESTRING(10 3,15 3)");
+    assert!(ln.intersects(&mln));
+    assert_eq!(ln.intersection(&mln), vec![Point::new(10., 3.)]);
+    assert!(!ln.intersects(&mpt));
+    assert_eq!(ln.distance(&mpt), 7.);
+
+    let ply = Polygon::from_wkt("POLYGON((24 4,24 8,30 8,30 4,24 4))");
+    assert!(ply.intersects(&mply));
+    assert!(mply.intersects(&ply));
+    assert_eq!(ply.intersection(&mply).len(), 4);
+    assert_eq!(mpt.intersection(&mply), vec![Point::new(10., 10.)]);
+
+    assert_eq!(
+        MultiPoint::from_wkt_checked("MULTIPOINT EMPTY").unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 1, found: 0 }
+    );
+    assert_eq!(
+        MultiLineString::from_wkt_checked("MULTILINESTRING((0 0,5 5),(10 0))").unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 2, found: 1 }
+    );
+    assert_eq!(
+        MultiPolygon::from_wkt_checked("MULTIPOLYGON(((0 0,0 10,10 10,10 0)))").unwrap_err(),
+        GeomError::UnclosedRing { ring: 0 }
+    );
+    assert_eq!(
+        MultiPolygon::from_wkt_checked("POLYGON((0 0,0 10,10 10,10 0,0 0))").unwrap_err(),
+        GeomError::WrongGeomType { expected: GeomType::MultiPolygon, found: GeomType::Polygon }
+    );
+}
+
+#[test]
+fn test_empty_multi_geometries() {
+    let ply = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0))");
+    let mpt = MultiPoint(vec![]);
+    let mln = MultiLineString(vec![]);
+    let mply = MultiPolygon::from(vec![]);
+    let empties: Vec<&dyn Geometry> = vec![&mpt, &mln, &mply];
+    for g in empties {
+        let mbr = g.bbox();
+        assert!(mbr.ll()[0].is_nan() && mbr.ur()[1].is_nan());
+        assert!(!g.intersects(&ply));
+        assert!(!ply.intersects(g));
+        assert!(g.intersection(&ply).is_empty());
+        assert!(g.distance(&ply).is_nan());
+        assert!(ply.distance(g).is_nan());
+    }
+}
+
+#[test]
+fn test_empty_geometry_collection() {
+    let ply = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0))");
+    let gc = GeometryCollection(vec![]);
+    assert!(gc.bbox().ll()[0].is_nan());
+    assert!(!gc.intersects(&ply) && !ply.intersects(&gc));
+    assert!(gc.intersection(&ply).is_empty());
+    assert!(gc.distance(&ply).is_nan());
+    assert!(ply.distance(&gc).is_nan());
+    assert!(gc.distance(&MultiPoint(vec![])).is_nan());
+    assert_eq!(gc.area(), 0.);
+
+    //empty members do not widen the bounds of the others
+    let pt = Point::new(20., 30.);
+    let gc = GeometryCollection(vec![Box::new(gc), Box::new(MultiPolygon(vec![])), Box::new(pt)]);
+    assert_eq!(gc.bbox(), pt.bbox());
+    assert_eq!(gc.distance(&ply), pt.distance(&ply));
+}
+
+#[test]
+fn test_wkt_nesting_limit() {
+    let nested = |n: usize| format!("{}POINT(3 4){}", "GEOMETRYCOLLECTION(".repeat(n), ")".repeat(n));
+    let gc = GeometryCollection::from_wkt_checked(&nested(63)).unwrap();
+    assert_eq!(gc.geometries().len(), 1);
+    match parse_wkt(&nested(64)) {
+        Err(GeomError::Parse { position, .. }) => assert_eq!(position, 64 * 19),
+        _ => unreachable!(),
+    }
+    //deep enough to overflow the stack if parsed recursively
+    assert!(read_wkt(&nested(200_000)).is_err());
+    assert!(GeometryCollection::from_wkt_checked(&nested(200_000)).is_err());
+}
+
+#[test]
+fn test_geometry_collection() {
+    let wkt = "GEOMETRYCOLLECTION(POINT(3 4),LINESTRING(0 0,5 5),\
+    GEOMETRYCOLLECTION(POLYGON((20 0,20 5,25 5,25 0,20 0)),MULTIPOINT((30 30),(31 31))))";
+    let gc = GeometryCollection::from_wkt(wkt);
+    assert!(gc.geom_type().is_geometry_collection());
+    assert!(gc.geom_type().is_multi());
+    assert_eq!(gc.geometries().len(), 3);
+    assert!(gc.geometries()[2].geom_type().is_geometry_collection());
+    assert_eq!(gc.wkt(), wkt);
+    let gc_clone = gc.clone();
+    assert_eq!(gc_clone.wkt_string(), gc.wkt_string());
+    assert_eq!(GeometryCollection::from_wkt(&gc.wkt()).wkt(), gc.wkt());
+
+    assert!(gc.bbox().equals(&MBR::new_from_bounds([0., 0.], [31., 31.])));
+    assert_eq!(gc.area(), 25.);
+    assert_eq!(gc.as_linear().len(), 5);
+
+    let pt_in = Point::new(22., 2.);
+    let ln = LineString::from_wkt("LINESTRING(0 5,5 0)");
+    let pt_out = Point::new(15., 2.);
+    assert!(gc.intersects(&pt_in));
+    assert!(pt_in.intersects(&gc));
+    assert!(gc.intersects(&ln));
+    assert!(ln.intersects(&gc));
+    assert_eq!(ln.intersection(&gc), vec![Point::new(2.5, 2.5)]);
+    assert!(!gc.intersects(&pt_out));
+    assert_eq!(gc.distance(&pt_out), 5.);
+    assert_eq!(pt_out.distance(&gc), 5.);
+    assert_eq!(gc.distance(&pt_in), 0.);
+
+    let geoms = vec![
+        "POINT(3 4)", "LINESTRING(0 0,5 5)", "POLYGON((20 0,20 5,25 5,25 0,20 0))",
+        "MULTIPOINT((30 30),(31 31))", "MULTILINESTRING((0 0,5 5),(10 0,10 5))",
+        "MULTIPOLYGON(((20 0,20 5,25 5,25 0,20 0)))", "GEOMETRYCOLLECTION(POINT(3 4))",
+    ];
+    for wkt in geoms.iter() {
+        assert_eq!(read_wkt(wkt).unwrap().wkt_string(), *wkt);
+    }
+    assert_eq!(
+        GeometryCollection::from_wkt_checked("GEOMETRYCOLLECTION EMPTY").unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 1, found: 0 }
+    );
+    assert_eq!(
+        GeometryCollection::from_wkt_checked("GEOMETRYCOLLECTION(POINT(3 4),LINESTRING(0 0))").unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 2, found: 1 }
+    );
+}
+
+#[test]
+fn test_named_predicates() {
+    let zone = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0),(4 4,4 6,6 6,6 4,4 4))");
+    let lot = Polygon::from_wkt("POLYGON((1 1,1 3,3 3,3 1,1 1))");
+    let edge_lot = Polygon::from_wkt("POLYGON((0 0,0 3,3 3,3 0,0 0))");
+    let in_hole = Polygon::from_wkt("POLYGON((4.5 4.5,4.5 5.5,5.5 5.5,5.5 4.5,4.5 4.5))");
+    let neighbour = Polygon::from_wkt("POLYGON((10 0,10 10,20 10,20 0,10 0))");
+    let straddle = Polygon::from_wkt("POLYGON((8 8,8 12,12 12,12 8,8 8))");
+
+    assert!(zone.contains(&lot) && lot.within(&zone));
+    assert!(zone.covers(&lot) && lot.covered_by(&zone));
+    //shares boundary : covered but not properly inside
+    assert!(zone.contains(&edge_lot) && zone.covers(&edge_lot));
+    //inside the hole : outside the zone
+    assert!(!zone.contains(&in_hole) && zone.disjoint(&in_hole));
+    assert!(zone.touches(&neighbour) && !zone.overlaps(&neighbour));
+    assert!(zone.overlaps(&straddle) && !zone.contains(&straddle));
+    assert!(!zone.disjoint(&straddle));
+
+    //boundary points : covered, not contained
+    let fence = Point::new(0., 5.);
+    let hole_fence = Point::new(4., 5.);
+    assert!(!zone.contains(&fence) && zone.covers(&fence) && zone.touches(&fence));
+    assert!(!zone.contains(&hole_fence) && zone.covers(&hole_fence));
+    assert!(zone.contains(&Point::new(2., 5.)));
+    assert!(!zone.covers(&Point::new(5., 5.)));
+
+    let road = LineString::from_wkt("LINESTRING(-5 2,15 2)");
+    assert!(road.crosses(&zone) && zone.crosses(&road));
+    let path = LineString::from_wkt("LINESTRING(0 -5,0 15)");
+    assert!(path.touches(&zone) && !path.crosses(&zone));
+    let other_road = LineString::from_wkt("LINESTRING(5 -5,5 15)");
+    assert!(road.crosses(&other_road));
+    let shared = LineString::from_wkt("LINESTRING(10 2,20 2)");
+    assert!(road.overlaps(&shared) && !road.crosses(&shared));
+
+    let ring_a = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0))");
+    let ring_b = Polygon::from_wkt("POLYGON((10 10,10 0,0 0,0 10,10 10))");
+    assert!(ring_a.equals_topo(&ring_b));
+    assert!(!ring_a.equals_topo(&lot));
+    let pt: &dyn Geometry = &Point::new(1., 2.);
+    assert!(pt.equals_topo(&Point::new(1., 2.)) && pt.within(&ring_a));
+    //reachable on a point next to its coordinate equality
+    let (a, b) = (Point::new(1., 2.), Point::new(1., 2.));
+    assert!(a.equals(&b) && a.equals_topo(&b) && a.equals_topo(&MultiPoint(vec![b, b])));
+    assert!(!a.equals_topo(&LineString::from_wkt("LINESTRING(1 2,3 4)")));
+    assert_eq!((pt.dimension(), road.dimension(), zone.dimension()), (0, 1, 2));
+}
+
+#[test]
+fn test_locate_point() {
+    use crate::Location;
+    let ply = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0),(4 4,4 6,6 6,6 4,4 4))");
+    assert_eq!(ply.locate_point(&Point::new(2., 2.)), Location::Interior);
+    assert_eq!(ply.locate_point(&Point::new(0., 3.)), Location::Boundary);
+    assert_eq!(ply.locate_point(&Point::new(10., 10.)), Location::Boundary);
+    assert_eq!(ply.locate_point(&Point::new(5., 4.)), Location::Boundary);
+    assert_eq!(ply.locate_point(&Point::new(6., 6.)), Location::Boundary);
+    assert_eq!(ply.locate_point(&Point::new(5., 5.)), Location::Exterior);
+    assert_eq!(ply.locate_point(&Point::new(10.5, 5.)), Location::Exterior);
+    //collinear with an edge, beyond its end
+    assert_eq!(ply.locate_point(&Point::new(0., 12.)), Location::Exterior);
+    //ray through a vertex
+    assert_eq!(ply.locate_point(&Point::new(-1., 10.)), Location::Exterior);
+    assert_eq!(ply.locate_point(&Point::new(2., 6.)), Location::Interior);
+
+    //slanted fence line : exact on edge test
+    let ring = LinearRing::new(vec![
+        Point::new(0., 0.), Point::new(1., 0.5), Point::new(0., 1.),
+    ]);
+    assert_eq!(ring.locate_point(&Point::new(0.5, 0.25)), Location::Boundary);
+    assert_eq!(ring.locate_point(&Point::new(0.5, 0.25 + 1e-15)), Location::Interior);
+    assert_eq!(ring.locate_point(&Point::new(0.5, 0.25 - 1e-15)), Location::Exterior);
+    assert_eq!(ring.locate_point(&Point::new(1., 0.5)), Location::Boundary);
+}
+
+#[test]
+fn test_centroid() {
+    use crate::{Location, Segment, MultiPoint, MultiPolygon, GeometryCollection};
+    let close = |a: Point, x: f64, y: f64| (a.x - x).abs() < 1e-9 && (a.y - y).abs() < 1e-9;
+
+    let pt = Point::new(3., 4.);
+    assert!(close(pt.centroid(), 3., 4.));
+    assert!(close(pt.interior_point(), 3., 4.));
+    let seg = Segment::new(Point::new(0., 0.), Point::new(4., 2.));
+    assert!(close(seg.centroid(), 2., 1.));
+    //length weighted : long leg dominates
+    let ln = LineString::from_wkt("LINESTRING(0 0,10 0,10 2)");
+    assert!(close(ln.centroid(), (50. + 20.) / 12., 2. / 12.));
+    assert!(close(ln.interior_point(), 10., 0.));
+    let mpt = MultiPoint::from_wkt("MULTIPOINT((0 0),(2 0),(4 6))");
+    assert!(close(mpt.centroid(), 2., 2.));
+    assert!(close(mpt.interior_point(), 2., 0.));
+
+    //area weighted with a hole, orientation free
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,1 5,5 5,5 1,1 1))");
+    let c = ply.centroid();
+    assert!(close(c, (500. - 16. * 3.) / 84., (500. - 16. * 3.) / 84.));
+    let rev = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0),(1 1,5 1,5 5,1 5,1 1))");
+    assert!(close(rev.centroid(), c.x, c.y));
+    assert_eq!(ply.locate_point(&ply.interior_point()), Location::Interior);
+
+    //centroid outside a concave polygon, interior point stays in
+    let u = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,8 10,8 2,2 2,2 10,0 10,0 0))");
+    assert_ne!(u.locate_point(&u.centroid()), Location::Interior);
+    assert_eq!(u.locate_point(&u.interior_point()), Location::Interior);
+    //hole over the middle of the bounds, vertex on the scan line
+    let ring = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 5,0 0),(1 1,9 1,9 9,1 9,1 1))");
+    assert_eq!(ring.locate_point(&ring.interior_point()), Location::Interior);
+
+    let mply = MultiPolygon::from_wkt("MULTIPOLYGON(((0 0,2 0,2 2,0 2,0 0)),((10 0,14 0,14 4,10 4,10 0)))");
+    assert!(close(mply.centroid(), (4. * 1. + 16. * 12.) / 20., (4. * 1. + 16. * 2.) / 20.));
+    assert_eq!(mply.polygons()[1].locate_point(&mply.interior_point()), Location::Interior);
+
+    //collection : highest dimension wins
+    let gc = GeometryCollection::from_wkt("GEOMETRYCOLLECTION(POINT(100 100),LINESTRING(0 0,0 4),POLYGON((0 0,2 0,2 2,0 2,0 0)))");
+    assert!(close(gc.centroid(), 1., 1.));
+    //collapsed polygon falls back to its boundary length
+    let flat = Polygon::from_wkt("POLYGON((0 0,2 0,4 0,0 0))");
+    assert!(close(flat.centroid(), 2., 0.));
+    assert!(close(flat.interior_point(), 2., 0.));
+}
+
+#[test]
+fn test_orientation() {
+    let ccw = LinearRing::new(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(4., 3.), Point::new(0., 3.)]);
+    assert_eq!(ccw.signed_area(), 12.);
+    assert!(ccw.is_ccw());
+    let cw = ccw.reversed();
+    assert_eq!(cw.signed_area(), -12.);
+    assert!(!cw.is_ccw());
+    assert_eq!(cw.area(), ccw.area());
+
+    //shell clockwise, hole counter clockwise
+    let ply = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0),(4 4,6 4,6 6,4 6,4 4))");
+    assert!(!ply.shell().is_ccw() && ply.holes()[0].is_ccw());
+    let rfc = ply.orient(true);
+    assert!(rfc.shell().is_ccw() && !rfc.holes()[0].is_ccw());
+    assert_eq!(rfc.wkt(), "POLYGON((0 0,10 0,10 10,0 10,0 0),(4 4,4 6,6 6,6 4,4 4))");
+    assert_eq!(rfc.area(), ply.area());
+    let shp = rfc.orient(false);
+    assert_eq!(shp.wkt(), ply.wkt());
+    assert_eq!(ply.orient(false).wkt(), ply.wkt());
+}
+
+#[test]
+fn test_line_simplicity() {
+    let ln = LineString::from_wkt("LINESTRING(0 0,4 0,4 4,8 4)");
+    assert!(ln.is_simple());
+    assert!(ln.self_intersections().is_empty());
+    //repeated points are not meetings of consecutive segments
+    assert!(LineString::from_wkt("LINESTRING(0 0,4 0,4 0,4 4)").is_simple());
+    //closed ring meets itself only at its endpoints
+    assert!(LineString::from_wkt("LINESTRING(0 0,4 0,4 4,0 4,0 0)").is_simple());
+
+    //track looping over itself
+    let ln = LineString::from_wkt("LINESTRING(0 0,4 0,4 4,2 4,2 -2)");
+    let pts: Vec<Point> = ln.self_intersections().iter().map(|ip| ip.pt).collect();
+    assert_eq!(pts, vec![Point::new(2., 0.)]);
+    assert!(!ln.is_simple());
+
+    //endpoint on the interior of the line
+    let ln = LineString::from_wkt("LINESTRING(0 0,4 0,4 4,2 0)");
+    let ips = ln.self_intersections();
+    assert_eq!(ips.len(), 1);
+    assert_eq!(ips[0].pt, Point::new(2., 0.));
+    assert!(ips[0].is_vertex());
+
+    //consecutive segments folding back over each other
+    let ln = LineString::from_wkt("LINESTRING(0 0,4 0,2 0)");
+    assert_eq!(ln.self_intersections().iter().map(|ip| ip.pt).collect::<Vec<_>>(), vec![Point::new(2., 0.)]);
+    //ring pinched at a vertex, and a closed line through its start
+    assert!(!LineString::from_wkt("LINESTRING(0 0,2 2,4 0,4 4,2 2,0 4,0 0)").is_simple());
+    assert!(!LineString::from_wkt("LINESTRING(0 0,4 0,4 4,0 0,-2 2,0 0)").is_simple());
+    assert!(Segment::new(Point::new(0., 0.), Point::new(1., 1.)).is_simple());
+}
+
+#[test]
+fn test_linear_referencing() {
+    let ln = LineString::from_wkt("LINESTRING(0 0,10 0,10 0,10 10,0 10)");
+    assert_eq!(ln.interpolate(5.), Point::new(5., 0.));
+    assert_eq!(ln.interpolate(10.), Point::new(10., 0.));
+    assert_eq!(ln.interpolate(15.), Point::new(10., 5.));
+    assert_eq!(ln.interpolate(-1.), Point::new(0., 0.));
+    assert_eq!(ln.interpolate(100.), Point::new(0., 10.));
+    assert_eq!(ln.interpolate_normalized(0.5), Point::new(10., 5.));
+
+    assert_eq!(ln.locate_point(&Point::new(12., 4.)), 14.);
+    assert_eq!(ln.locate_point(&Point::new(-3., 2.)), 0.);
+    assert_eq!(ln.locate_point(&Point::new(4., 11.)), 26.);
+    //equally close to the first and last segments
+    assert_eq!(ln.locate_point(&Point::new(3., 5.)), 3.);
+
+    assert_eq!(ln.substring(5., 25.).wkt(), "LINESTRING(5 0,10 0,10 10,5 10)");
+    assert_eq!(ln.substring(10., 20.).wkt(), "LINESTRING(10 0,10 10)");
+    assert_eq!(ln.substring(25., 5.).wkt(), "LINESTRING(5 10,10 10,10 0,5 0)");
+    assert_eq!(ln.substring(-5., 50.).wkt(), "LINESTRING(0 0,10 0,10 10,0 10)");
+    assert_eq!(ln.substring(12., 12.).wkt(), "LINESTRING(10 2,10 2)");
+
+    //long winding line over many monotone chains
+    let pts: Vec<Point> = (0..2000).map(|i| {
+        let x = i as f64;
+        Point::new(x, if i % 4 < 2 { 0. } else { 1. })
+    }).collect();
+    let ln = LineString::new(pts);
+    let d = ln.len(0, 1500) + 0.25;
+    let pt = ln.interpolate(d);
+    assert!((ln.locate_point(&pt) - d).abs() < 1e-9);
+    assert!((ln.substring(d, d + 10.).length() - 10.).abs() < 1e-9);
+    //far from the line and on its last vertex
+    assert_eq!(ln.locate_point(&Point::new(-1000., 0.)), 0.);
+    assert!((ln.locate_point(&Point::new(1999., 1000.)) - ln.length()).abs() < 1e-9);
+
+    //measures follow edits to the coordinates
+    let mut ln = LineString::from_wkt("LINESTRING(0 0,10 0)");
+    ln.coordinates.push(Point::new(10., 10.));
+    ln.re_construct();
+    assert_eq!(ln.interpolate(15.), Point::new(10., 5.));
+    assert_eq!(ln.locate_point(&Point::new(11., 8.)), 18.);
+
+    let ln = LineString::from_point(Point::new(3., 4.));
+    assert_eq!(ln.locate_point(&Point::new(0., 0.)), 0.);
+    assert_eq!(ln.interpolate(1.), Point::new(3., 4.));
+
+    //bad input gives NaN rather than searching forever
+    let ln = LineString::from_wkt("LINESTRING(0 0,10 0)");
+    assert!(ln.locate_point(&Point::new(f64::NAN, 1.)).is_nan());
+    assert!(ln.locate_point(&Point::new(1., f64::INFINITY)).is_nan());
+    assert!(ln.locate_point(&Point::new(f64::NEG_INFINITY, f64::INFINITY)).is_nan());
+    let ln = LineString::new(vec![Point::new(0., 0.), Point::new(f64::NAN, 1.)]);
+    assert!(ln.locate_point(&Point::new(1., 1.)).is_nan());
+}
+
+#[test]
+fn test_default_wkb_and_geojson() {
+    //a geometry from outside the crate, with none of the encoders implemented
+    #[derive(Clone)]
+    struct Parcel(Polygon);
+
+    impl Geometry for Parcel {
+        fn bbox(&self) -> MBR { self.0.bbox() }
+        fn as_linear(&self) -> Vec<LineString> { self.0.as_linear() }
+        fn wkt_string(&self) -> String { self.0.wkt_string() }
+        fn geom_type(&self) -> GeomType { GeomType::Polygon }
+        fn intersects(&self, other: &dyn Geometry) -> bool { self.0.intersects(other) }
+        fn intersection(&self, other: &dyn Geometry) -> Vec<Point> { self.0.intersection(other) }
+        fn distance(&self, other: &dyn Geometry) -> f64 { self.0.distance(other) }
+    }
+
+    let ply = Polygon::from_wkt("POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,4 2,4 4,2 4,2 2))");
+    let parcel = Parcel(ply.clone());
+    assert_eq!(parcel.wkb_bytes(ByteOrder::LittleEndian), ply.wkb_bytes(ByteOrder::LittleEndian));
+    assert_eq!(parcel.geojson_value(), ply.geojson_value());
+
+    //components of mixed dimensions come out as a collection
+    let topo = Topology {
+        points: vec![Point::new(20., 20.)],
+        lines: vec![pts![[0., 0.], [5., 5.]], pts![[1., 0.], [6., 5.]]],
+        polygons: vec![],
+    };
+    assert_eq!(topo.into_geometry().wkt_string(), "GEOMETRYCOLLECTION(POINT(20 20),MULTILINESTRING((0 0,5 5),(1 0,6 5)))");
+}
@@ -22,6 +22,11 @@ impl Sign {
     }
 }
 
+///Bounds of an empty geometry, NaN on every side
+pub fn empty_mbr() -> MBR {
+    MBR::new(f64::NAN, f64::NAN, f64::NAN, f64::NAN)
+}
+
 pub fn process_chains(coordinates: &Vec<Point>) -> (MonoMBR, Vec<MonoMBR>) {
     let n = coordinates.len();
     let (i, j) = (0usize, n - 1);
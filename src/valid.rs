@@ -0,0 +1,339 @@
+use crate::{Point, LineString, LinearRing, Polygon, Location, Topology, segment};
+use crate::inter::InterPoint;
+use crate::overlay::{build_polygons, node_point, node_self, turn, union_all};
+use crate::relate::{locate_in_ring, signed_area};
+use crate::{centroid, noding};
+use std::collections::{BTreeMap, BTreeSet};
+
+///Reason a geometry is invalid
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ValidityErrorKind {
+    ///Edges cross or overlap
+    SelfIntersection,
+    ///Ring passes twice through the same point
+    RingSelfTouch,
+    HoleOutsideShell,
+    ///Hole inside another hole
+    NestedHoles,
+    ///Holes and shell touch so that the interior falls apart
+    InteriorDisconnected,
+    TooFewPoints,
+    ///Consecutive equal coordinates
+    RepeatedPoints,
+    NonFiniteCoordinates,
+}
+
+impl std::fmt::Display for ValidityErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let kind = match self {
+            ValidityErrorKind::SelfIntersection => "self-intersection",
+            ValidityErrorKind::RingSelfTouch => "ring self-touch",
+            ValidityErrorKind::HoleOutsideShell => "hole outside shell",
+            ValidityErrorKind::NestedHoles => "nested holes",
+            ValidityErrorKind::InteriorDisconnected => "interior disconnected",
+            ValidityErrorKind::TooFewPoints => "too few points",
+            ValidityErrorKind::RepeatedPoints => "repeated points",
+            ValidityErrorKind::NonFiniteCoordinates => "non-finite coordinates",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+///Validity error and where it was found
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ValidityError {
+    pub kind: ValidityErrorKind,
+    pub location: Point,
+}
+
+impl ValidityError {
+    pub fn new(kind: ValidityErrorKind, location: Point) -> ValidityError {
+        ValidityError { kind, location }
+    }
+}
+
+impl std::fmt::Display for ValidityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{} at {}", self.kind, self.location.fmt_xy())
+    }
+}
+
+impl std::error::Error for ValidityError {}
+
+///Line errors : non-finite coordinates, repeated points, fewer than 2 distinct points
+pub fn line_errors(coords: &[Point]) -> Vec<ValidityError> {
+    let mut errs = vec![];
+    coordinate_errors(coords, 2, &mut errs);
+    errs
+}
+
+///Ring errors : those of a line, fewer than 4 coordinates, crossing or touching edges
+pub fn ring_errors(coords: &[Point]) -> Vec<ValidityError> {
+    let mut errs = vec![];
+    if coordinate_errors(coords, 4, &mut errs) {
+        ring_self_intersections(&distinct(coords), &mut errs);
+    }
+    dedup(errs)
+}
+
+///Polygon errors : those of its rings, rings crossing each other, holes outside the shell
+///or inside each other, and touching rings that split the interior
+pub fn polygon_errors(rings: &[LinearRing]) -> Vec<ValidityError> {
+    let mut errs = vec![];
+    let mut valid = vec![];
+    for ring in rings.iter() {
+        let coords = ring.coordinates();
+        if coordinate_errors(coords, 4, &mut errs) {
+            let coords = distinct(coords);
+            ring_self_intersections(&coords, &mut errs);
+            valid.push(coords);
+        } else {
+            valid.push(vec![]);
+        }
+    }
+
+    //rings touching at a point
+    let mut touches: BTreeMap<Point, BTreeSet<usize>> = BTreeMap::new();
+    for a in 0..valid.len() {
+        for b in a + 1..valid.len() {
+            if valid[a].is_empty() || valid[b].is_empty() {
+                continue;
+            }
+            for pt in ring_intersections(&valid[a], &valid[b], &mut errs) {
+                let rs = touches.entry(pt).or_default();
+                rs.insert(a);
+                rs.insert(b);
+            }
+        }
+    }
+    //rings and touch points form a forest, a cycle cuts off part of the interior
+    let mut forest = UnionFind::new(rings.len());
+    for (pt, rs) in touches.into_iter() {
+        let node = forest.add();
+        for r in rs.into_iter() {
+            if !forest.union(r, node) {
+                errs.push(ValidityError::new(ValidityErrorKind::InteriorDisconnected, pt));
+            }
+        }
+    }
+
+    if let Some(shell) = valid.first().filter(|s| !s.is_empty()) {
+        for (i, hole) in valid.iter().enumerate().skip(1).filter(|(_, h)| !h.is_empty()) {
+            if let Some((pt, Location::Exterior)) = inside_point(hole, shell) {
+                errs.push(ValidityError::new(ValidityErrorKind::HoleOutsideShell, pt));
+            }
+            for other in valid.iter().skip(1).take(i - 1).filter(|h| !h.is_empty()) {
+                let nested = [(hole, other), (other, hole)].iter().find_map(|(h, o)| {
+                    match inside_point(h, o) {
+                        Some((pt, Location::Interior)) => Some(pt),
+                        _ => None,
+                    }
+                });
+                if let Some(pt) = nested {
+                    errs.push(ValidityError::new(ValidityErrorKind::NestedHoles, pt));
+                }
+            }
+        }
+    }
+    dedup(errs)
+}
+
+///Valid polygons covering the area of ply : each ring is noded at its self intersections
+///and split into the faces it encloses (even-odd), the shell faces are merged and the
+///hole faces taken out. Non-finite and repeated coordinates are dropped.
+pub fn make_valid(ply: &Polygon) -> Vec<Polygon> {
+    if ply.is_valid() {
+        return vec![ply.clone()];
+    }
+    let mut faces = ply.0.iter().map(|ring| {
+        let mut coords: Vec<Point> = ring.coordinates().iter()
+            .filter(|pt| pt.x.is_finite() && pt.y.is_finite())
+            .cloned()
+            .collect();
+        coords.dedup_by(|p, q| p.equals(q));
+        if coords.len() > 2 && !coords[0].equals(&coords[coords.len() - 1]) {
+            coords.push(coords[0]);
+        }
+        if coords.len() < 4 {
+            vec![]
+        } else {
+            ring_faces(&coords)
+        }
+    });
+    let shells = union_all(faces.next().unwrap_or_default());
+    let holes = union_all(faces.flatten().collect());
+    let mut result = shells;
+    for hole in holes.iter() {
+        result = result.iter().flat_map(|p| p.difference(hole)).collect();
+    }
+    result
+}
+
+//faces of the noded ring lying inside it by the even-odd rule, a face around a hole
+//touching it at a node is traced as one ring and split back into shell and hole
+fn ring_faces(coords: &[Point]) -> Vec<Polygon> {
+    let mut segs = BTreeSet::new();
+    for split in node_self(coords).iter() {
+        for w in split.windows(2) {
+            let key = if w[0] < w[1] { (w[0], w[1]) } else { (w[1], w[0]) };
+            segs.insert(key);
+        }
+    }
+    let edges: Vec<(Point, Point)> = segs.iter().flat_map(|&(p, q)| vec![(p, q), (q, p)]).collect();
+    let faces = trace_faces(&edges).into_iter()
+        .filter(|face| signed_area(face) > 0.0)
+        .filter(|face| {
+            let topo = Topology { polygons: vec![vec![face.clone()]], ..Default::default() };
+            locate_in_ring(coords, &centroid::interior_point(&topo)) == Location::Interior
+        })
+        .collect();
+    build_polygons(faces)
+}
+
+//faces of a planar graph given both directions of each edge : at every node the walk takes
+//the sharpest left turn, bounded faces come out counter clockwise
+pub(crate) fn trace_faces(edges: &[(Point, Point)]) -> Vec<Vec<Point>> {
+    let mut out: BTreeMap<Point, Vec<usize>> = BTreeMap::new();
+    for (i, (p, _)) in edges.iter().enumerate() {
+        out.entry(*p).or_default().push(i);
+    }
+    let mut used = vec![false; edges.len()];
+    let mut faces = vec![];
+    for start in 0..edges.len() {
+        if used[start] {
+            continue;
+        }
+        let mut face = vec![edges[start].0];
+        let mut e = start;
+        while !used[e] {
+            used[e] = true;
+            let (prev, cur) = edges[e];
+            face.push(cur);
+            let d = Point::new(cur.x - prev.x, cur.y - prev.y);
+            e = *out[&cur].iter().max_by(|&&i, &&j| {
+                turn(&d, &cur, &edges[i].1).partial_cmp(&turn(&d, &cur, &edges[j].1)).unwrap()
+            }).unwrap();
+        }
+        if e == start && face.len() >= 4 {
+            faces.push(face);
+        }
+    }
+    faces
+}
+
+//pushes coordinate errors, true if the geometry is fit for further checks
+fn coordinate_errors(coords: &[Point], min_size: usize, errs: &mut Vec<ValidityError>) -> bool {
+    if let Some(pt) = coords.iter().find(|pt| !pt.x.is_finite() || !pt.y.is_finite()) {
+        errs.push(ValidityError::new(ValidityErrorKind::NonFiniteCoordinates, *pt));
+        return false;
+    }
+    for w in coords.windows(2).filter(|w| w[0].equals(&w[1])) {
+        errs.push(ValidityError::new(ValidityErrorKind::RepeatedPoints, w[0]));
+    }
+    //a ring needs three distinct points and its closing point
+    if distinct(coords).len() < min_size {
+        let location = coords.first().cloned().unwrap_or_else(|| Point::new(f64::NAN, f64::NAN));
+        errs.push(ValidityError::new(ValidityErrorKind::TooFewPoints, location));
+        return false;
+    }
+    true
+}
+
+fn distinct(coords: &[Point]) -> Vec<Point> {
+    let mut coords = coords.to_vec();
+    coords.dedup_by(|p, q| p.equals(q));
+    coords
+}
+
+fn dedup(errs: Vec<ValidityError>) -> Vec<ValidityError> {
+    let mut out: Vec<ValidityError> = vec![];
+    for e in errs.into_iter() {
+        if !out.iter().any(|o| o.kind == e.kind && o.location.equals(&e.location)) {
+            out.push(e);
+        }
+    }
+    out
+}
+
+//crossings and overlaps are intersections, other common vertices are self touches
+fn ring_self_intersections(coords: &[Point], errs: &mut Vec<ValidityError>) {
+    let n = coords.len() - 1;
+    let found = noding::intersections(&[LineString::new(coords.to_vec())]);
+    //one error per pair of segments, intersections come sorted by pair
+    for pair in found.chunk_by(|x, y| (x.seg_a, x.seg_b) == (y.seg_a, y.seg_b)) {
+        let (i, j) = (pair[0].seg_a, pair[0].seg_b);
+        let adjacent = j == i + 1 || (i == 0 && j == n - 1);
+        let ip = pair[0].point;
+        //an overlap, or adjacent segments meeting past their common vertex as they fold back
+        let kind = if pair.len() > 1 || adjacent || !ip.is_vertex() {
+            ValidityErrorKind::SelfIntersection
+        } else {
+            ValidityErrorKind::RingSelfTouch
+        };
+        errs.push(ValidityError::new(kind, ip.pt));
+    }
+}
+
+//touch points of two rings, crossings and overlaps are pushed as errors
+fn ring_intersections(a: &[Point], b: &[Point], errs: &mut Vec<ValidityError>) -> Vec<Point> {
+    let (la, lb) = (LineString::new(a.to_vec()), LineString::new(b.to_vec()));
+    let mut touches = vec![];
+    for (i, j) in la.segment_pairs(&lb) {
+        let (a0, a1, b0, b1) = (a[i], a[i + 1], b[j], b[j + 1]);
+        let ips: Vec<InterPoint> = segment::intersection(&a0, &a1, &b0, &b1);
+        match ips.len() {
+            0 => {}
+            1 if ips[0].is_vertex() => touches.push(node_point(&ips[0], &a0, &a1, &b0, &b1)),
+            _ => errs.push(ValidityError::new(ValidityErrorKind::SelfIntersection, node_point(&ips[0], &a0, &a1, &b0, &b1))),
+        }
+    }
+    touches
+}
+
+//first vertex or edge midpoint of ring off the boundary of other, and where it lies
+fn inside_point(ring: &[Point], other: &[Point]) -> Option<(Point, Location)> {
+    let mids = ring.windows(2).map(|w| Point::new((w[0].x + w[1].x) / 2.0, (w[0].y + w[1].y) / 2.0));
+    ring.iter().cloned().chain(mids)
+        .map(|pt| (pt, locate_in_ring(other, &pt)))
+        .find(|(_, loc)| *loc != Location::Boundary)
+}
+
+//disjoint sets over rings then touch points
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> UnionFind {
+        UnionFind { parent: (0..n).collect() }
+    }
+
+    fn add(&mut self) -> usize {
+        self.parent.push(self.parent.len());
+        self.parent.len() - 1
+    }
+
+    fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut i = i;
+        while self.parent[i] != root {
+            let next = self.parent[i];
+            self.parent[i] = root;
+            i = next;
+        }
+        root
+    }
+
+    //false if i and j were already connected
+    fn union(&mut self, i: usize, j: usize) -> bool {
+        let (ri, rj) = (self.find(i), self.find(j));
+        self.parent[ri] = rj;
+        ri != rj
+    }
+}
+
+#[cfg(test)]
+mod tests;
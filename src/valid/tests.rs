@@ -0,0 +1,136 @@
+use super::*;
+use crate::Polygon;
+use ValidityErrorKind::*;
+
+fn kinds(errs: &[ValidityError]) -> Vec<ValidityErrorKind> {
+    errs.iter().map(|e| e.kind).collect()
+}
+
+#[test]
+fn test_line_validity() {
+    let ln = LineString::from_wkt("LINESTRING(0 0,1 1,2 0)");
+    assert!(ln.is_valid());
+    //self crossing lines are valid, only not simple
+    assert!(LineString::from_wkt("LINESTRING(0 0,2 2,2 0,0 2)").is_valid());
+
+    let ln = LineString::from_wkt("LINESTRING(0 0,1 1,1 1,2 0)");
+    assert_eq!(ln.validation_errors(), vec![ValidityError::new(RepeatedPoints, Point::new(1., 1.))]);
+    let ln = LineString::from_point(Point::new(3., 4.));
+    assert_eq!(kinds(&ln.validation_errors()), vec![RepeatedPoints, TooFewPoints]);
+    let ln = LineString::new(vec![Point::new(0., 0.), Point::new(f64::NAN, 1.)]);
+    assert_eq!(kinds(&ln.validation_errors()), vec![NonFiniteCoordinates]);
+    assert_eq!(ValidityError::new(RepeatedPoints, Point::new(1., 2.5)).to_string(), "repeated points at 1 2.5");
+}
+
+#[test]
+fn test_ring_validity() {
+    let ring = LinearRing::new(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(4., 4.), Point::new(0., 4.)]);
+    assert!(ring.is_valid());
+
+    //bowtie
+    let ring = LinearRing::new(vec![Point::new(0., 0.), Point::new(4., 4.), Point::new(4., 0.), Point::new(0., 4.)]);
+    assert_eq!(ring.validation_errors(), vec![ValidityError::new(SelfIntersection, Point::new(2., 2.))]);
+
+    //figure eight through a vertex
+    let ring = LinearRing::new(vec![
+        Point::new(0., 0.), Point::new(2., 2.), Point::new(4., 0.), Point::new(4., 4.),
+        Point::new(2., 2.), Point::new(0., 4.),
+    ]);
+    assert_eq!(ring.validation_errors(), vec![ValidityError::new(RingSelfTouch, Point::new(2., 2.))]);
+
+    //spike folding back on itself, its tip lies on an earlier edge
+    let ring = LinearRing::new(vec![
+        Point::new(0., 0.), Point::new(4., 0.), Point::new(6., 0.), Point::new(5., 0.), Point::new(4., 4.),
+    ]);
+    assert_eq!(ring.validation_errors(), vec![
+        ValidityError::new(SelfIntersection, Point::new(5., 0.)),
+        ValidityError::new(RingSelfTouch, Point::new(5., 0.)),
+    ]);
+
+    let ring = LinearRing::new(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(0., 0.)]);
+    assert_eq!(kinds(&ring.validation_errors()), vec![TooFewPoints]);
+}
+
+#[test]
+fn test_polygon_validity() {
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,4 2,4 4,2 4,2 2),(6 6,8 6,8 8,6 8,6 6))");
+    assert!(ply.is_valid());
+    //hole touching the shell at one point is fine
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(0 5,4 4,4 6,0 5))");
+    assert!(ply.is_valid());
+
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(12 2,14 2,14 4,12 4,12 2))");
+    assert_eq!(ply.validation_errors(), vec![ValidityError::new(HoleOutsideShell, Point::new(12., 2.))]);
+
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,9 1,9 9,1 9,1 1),(4 4,6 4,6 6,4 6,4 4))");
+    assert_eq!(ply.validation_errors(), vec![ValidityError::new(NestedHoles, Point::new(4., 4.))]);
+
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(5 5,15 5,15 6,5 6,5 5))");
+    //hole crossing the shell
+    assert_eq!(ply.validation_errors(), vec![
+        ValidityError::new(SelfIntersection, Point::new(10., 5.)),
+        ValidityError::new(SelfIntersection, Point::new(10., 6.)),
+    ]);
+
+    //hole touching the shell twice cuts the polygon in two
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(0 5,5 4,10 5,5 6,0 5))");
+    assert_eq!(ply.validation_errors(), vec![ValidityError::new(InteriorDisconnected, Point::new(10., 5.))]);
+    //chain of holes across the polygon
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(0 5,4 4,5 5,4 6,0 5),(5 5,9 4,10 5,9 6,5 5))");
+    assert_eq!(ply.validation_errors(), vec![ValidityError::new(InteriorDisconnected, Point::new(10., 5.))]);
+    assert!(Polygon::new(vec![ply.0[0].coordinates().clone(), ply.0[1].coordinates().clone()]).is_valid());
+}
+
+fn total_area(plys: &[Polygon]) -> f64 {
+    plys.iter().map(|p| crate::Geometry::area(p)).sum()
+}
+
+#[test]
+fn test_make_valid() {
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,4 2,4 4,2 4,2 2))");
+    assert_eq!(ply.make_valid()[0].wkt(), ply.wkt());
+
+    //bowtie splits into its two lobes
+    let bowtie = Polygon::from_wkt("POLYGON((0 0,4 4,4 0,0 4,0 0))");
+    let fixed = bowtie.make_valid();
+    assert_eq!(fixed.len(), 2);
+    assert!(fixed.iter().all(|p| p.is_valid()));
+    assert_eq!(total_area(&fixed), 8.);
+
+    //hole crossing its shell only removes the overlap
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(5 2,15 2,15 8,5 8,5 2))");
+    let fixed = ply.make_valid();
+    assert_eq!(fixed.len(), 1);
+    assert!(fixed[0].is_valid());
+    assert_eq!(total_area(&fixed), 100. - 30.);
+
+    //hole outside the shell is dropped, nested holes merge
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(12 2,14 2,14 4,12 4,12 2),(1 1,9 1,9 9,1 9,1 1),(4 4,6 4,6 6,4 6,4 4))");
+    let fixed = ply.make_valid();
+    assert_eq!(fixed.len(), 1);
+    assert_eq!(fixed[0].holes().len(), 1);
+    assert_eq!(total_area(&fixed), 100. - 64.);
+
+    //hole splitting the interior gives two polygons
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(0 5,5 4,10 5,5 6,0 5))");
+    let fixed = ply.make_valid();
+    assert_eq!(fixed.len(), 2);
+    assert!(fixed.iter().all(|p| p.is_valid()));
+    assert_eq!(total_area(&fixed), 100. - 10.);
+
+    //figure eight shell and repeated points
+    let ply = Polygon::from_wkt("POLYGON((0 0,2 2,4 0,4 0,4 4,2 2,0 4,0 0))");
+    let fixed = ply.make_valid();
+    assert_eq!(fixed.len(), 2);
+    assert!(fixed.iter().all(|p| p.is_valid()));
+    assert_eq!(total_area(&fixed), 8.);
+
+    //shell looping over itself : the doubly enclosed square is out by even-odd,
+    //it comes back as a hole touching the shell at a point
+    let ply = Polygon::from_wkt("POLYGON((0 0,10 0,10 10,0 10,0 2,8 2,8 8,2 8,2 0,0 0))");
+    let fixed = ply.make_valid();
+    assert_eq!(fixed.len(), 1);
+    assert_eq!(fixed[0].holes().len(), 1);
+    assert!(fixed[0].is_valid());
+    assert_eq!(total_area(&fixed), 100. - 4. - 36.);
+}
@@ -0,0 +1,331 @@
+use crate::{GeomType, GeomError, Geometry, Point, PointZ, LinearRing};
+use crate::wkt::{WKT, MAX_NESTING, to_geometry};
+
+pub const WKB_POINT: u32 = 1;
+pub const WKB_LINESTRING: u32 = 2;
+pub const WKB_POLYGON: u32 = 3;
+pub const WKB_MULTIPOINT: u32 = 4;
+pub const WKB_MULTILINESTRING: u32 = 5;
+pub const WKB_MULTIPOLYGON: u32 = 6;
+pub const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+//ISO : type + 1000 (Z), + 2000 (M), + 3000 (ZM)
+const ISO_Z: u32 = 1000;
+const ISO_M: u32 = 2000;
+const ISO_ZM: u32 = 3000;
+//EWKB : high bit flags on the type
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+///Byte order of a wkb buffer
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ByteOrder {
+    ///XDR
+    BigEndian,
+    ///NDR
+    LittleEndian,
+}
+
+///Encoding of the z dimension
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Dialect {
+    ///ISO SQL/MM : type code + 1000
+    Iso,
+    ///PostGIS extended wkb : type code | 0x80000000
+    Ewkb,
+}
+
+///Parse wkb buffer of any geometry type
+pub fn read_wkb(buf: &[u8]) -> Result<Box<dyn Geometry>, GeomError> {
+    to_geometry(parse_wkb(buf)?)
+}
+
+///Parse wkb buffer, z and m ordinates are dropped
+pub fn parse_wkb(buf: &[u8]) -> Result<WKT, GeomError> {
+    Reader::new(buf).geometry(1)
+}
+
+///Parse wkb buffer of a point with z ordinate (ISO or EWKB)
+pub fn read_point_z(buf: &[u8]) -> Result<PointZ, GeomError> {
+    let mut rdr = Reader::new(buf);
+    let (order, header) = rdr.header()?;
+    if header.code != WKB_POINT {
+        return Err(GeomError::WrongGeomType {
+            expected: GeomType::Point,
+            found: geom_type(header.code),
+        });
+    }
+    if !header.has_z {
+        return Err(GeomError::TooFewCoordinates { expected: 3, found: 2 });
+    }
+    let (x, y) = (rdr.f64(order)?, rdr.f64(order)?);
+    let z = rdr.f64(order)?;
+    if header.has_m {
+        rdr.f64(order)?;
+    }
+    Ok(PointZ::new(x, y, z))
+}
+
+///Encode point with z ordinate
+pub fn write_point_z(pt: &PointZ, byte_order: ByteOrder, dialect: Dialect) -> Vec<u8> {
+    let code = match dialect {
+        Dialect::Iso => WKB_POINT + ISO_Z,
+        Dialect::Ewkb => WKB_POINT | EWKB_Z,
+    };
+    let mut buf = Vec::with_capacity(29);
+    write_header(&mut buf, byte_order, code);
+    write_f64(&mut buf, byte_order, pt.x);
+    write_f64(&mut buf, byte_order, pt.y);
+    write_f64(&mut buf, byte_order, pt.z);
+    buf
+}
+
+///Writes byte order and geometry type
+pub fn write_header(buf: &mut Vec<u8>, byte_order: ByteOrder, code: u32) {
+    buf.push(match byte_order {
+        ByteOrder::BigEndian => 0u8,
+        ByteOrder::LittleEndian => 1u8,
+    });
+    write_u32(buf, byte_order, code);
+}
+
+pub fn write_u32(buf: &mut Vec<u8>, byte_order: ByteOrder, v: u32) {
+    match byte_order {
+        ByteOrder::BigEndian => buf.extend_from_slice(&v.to_be_bytes()),
+        ByteOrder::LittleEndian => buf.extend_from_slice(&v.to_le_bytes()),
+    }
+}
+
+pub fn write_f64(buf: &mut Vec<u8>, byte_order: ByteOrder, v: f64) {
+    match byte_order {
+        ByteOrder::BigEndian => buf.extend_from_slice(&v.to_be_bytes()),
+        ByteOrder::LittleEndian => buf.extend_from_slice(&v.to_le_bytes()),
+    }
+}
+
+///Encode point
+pub fn write_point(buf: &mut Vec<u8>, byte_order: ByteOrder, pt: &Point) {
+    write_header(buf, byte_order, WKB_POINT);
+    write_f64(buf, byte_order, pt.x);
+    write_f64(buf, byte_order, pt.y);
+}
+
+///Encode linestring
+pub fn write_linestring(buf: &mut Vec<u8>, byte_order: ByteOrder, coordinates: &[Point]) {
+    write_header(buf, byte_order, WKB_LINESTRING);
+    write_coordinates(buf, byte_order, coordinates);
+}
+
+///Encode polygon
+pub fn write_polygon(buf: &mut Vec<u8>, byte_order: ByteOrder, rings: &[LinearRing]) {
+    write_header(buf, byte_order, WKB_POLYGON);
+    write_u32(buf, byte_order, rings.len() as u32);
+    for r in rings.iter() {
+        write_coordinates(buf, byte_order, r.coordinates());
+    }
+}
+
+fn write_coordinates(buf: &mut Vec<u8>, byte_order: ByteOrder, coordinates: &[Point]) {
+    write_u32(buf, byte_order, coordinates.len() as u32);
+    for pt in coordinates.iter() {
+        write_f64(buf, byte_order, pt.x);
+        write_f64(buf, byte_order, pt.y);
+    }
+}
+
+fn geom_type(code: u32) -> GeomType {
+    match code {
+        WKB_POINT => GeomType::Point,
+        WKB_LINESTRING => GeomType::LineString,
+        WKB_POLYGON => GeomType::Polygon,
+        WKB_MULTIPOINT => GeomType::MultiPoint,
+        WKB_MULTILINESTRING => GeomType::MultiLineString,
+        WKB_MULTIPOLYGON => GeomType::MultiPolygon,
+        WKB_GEOMETRYCOLLECTION => GeomType::GeometryCollection,
+        _ => GeomType::Unknown,
+    }
+}
+
+struct Header {
+    code: u32,
+    has_z: bool,
+    has_m: bool,
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Reader<'a> {
+        Reader { buf, pos: 0 }
+    }
+
+    fn error(&self, message: &str) -> GeomError {
+        GeomError::Parse { message: message.into(), position: self.pos }
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], GeomError> {
+        if self.buf.len() - self.pos < n {
+            return Err(self.error("unexpected end of wkb buffer"));
+        }
+        let bytes = &self.buf[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    fn u32(&mut self, order: ByteOrder) -> Result<u32, GeomError> {
+        let mut b = [0u8; 4];
+        b.copy_from_slice(self.take(4)?);
+        Ok(match order {
+            ByteOrder::BigEndian => u32::from_be_bytes(b),
+            ByteOrder::LittleEndian => u32::from_le_bytes(b),
+        })
+    }
+
+    fn f64(&mut self, order: ByteOrder) -> Result<f64, GeomError> {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(self.take(8)?);
+        Ok(match order {
+            ByteOrder::BigEndian => f64::from_be_bytes(b),
+            ByteOrder::LittleEndian => f64::from_le_bytes(b),
+        })
+    }
+
+    //element count, rejects counts the remaining buffer cannot hold
+    fn count(&mut self, order: ByteOrder, min_size: usize) -> Result<usize, GeomError> {
+        let n = self.u32(order)? as usize;
+        if n > (self.buf.len() - self.pos) / min_size {
+            return Err(self.error("element count exceeds wkb buffer"));
+        }
+        Ok(n)
+    }
+
+    fn header(&mut self) -> Result<(ByteOrder, Header), GeomError> {
+        let order = match self.take(1)?[0] {
+            0 => ByteOrder::BigEndian,
+            1 => ByteOrder::LittleEndian,
+            _ => {
+                self.pos -= 1;
+                return Err(self.error("invalid wkb byte order"));
+            }
+        };
+        let mut code = self.u32(order)?;
+        let mut has_z = code & EWKB_Z != 0;
+        let mut has_m = code & EWKB_M != 0;
+        let has_srid = code & EWKB_SRID != 0;
+        code &= !(EWKB_Z | EWKB_M | EWKB_SRID);
+        if has_srid {
+            self.u32(order)?;
+        }
+        if code > ISO_ZM {
+            has_z = true;
+            has_m = true;
+            code -= ISO_ZM;
+        } else if code > ISO_M {
+            has_m = true;
+            code -= ISO_M;
+        } else if code > ISO_Z {
+            has_z = true;
+            code -= ISO_Z;
+        }
+        if geom_type(code) == GeomType::Unknown {
+            return Err(GeomError::Unsupported { geom: format!("wkb type {}", code) });
+        }
+        Ok((order, Header { code, has_z, has_m }))
+    }
+
+    fn coordinates(&mut self, order: ByteOrder, dim: usize) -> Result<Vec<Point>, GeomError> {
+        let n = self.count(order, dim * 8)?;
+        let mut coords = Vec::with_capacity(n);
+        for _ in 0..n {
+            coords.push(self.point(order, dim)?);
+        }
+        Ok(coords)
+    }
+
+    fn point(&mut self, order: ByteOrder, dim: usize) -> Result<Point, GeomError> {
+        let pt = Point::new(self.f64(order)?, self.f64(order)?);
+        for _ in 2..dim {
+            self.f64(order)?;
+        }
+        Ok(pt)
+    }
+
+    //depth counts the headers this one is nested in, itself included
+    fn geometry(&mut self, depth: usize) -> Result<WKT, GeomError> {
+        if depth > MAX_NESTING {
+            return Err(self.error(&format!("wkb nested deeper than {} levels", MAX_NESTING)));
+        }
+        let (order, header) = self.header()?;
+        let dim = 2 + header.has_z as usize + header.has_m as usize;
+        let geom_type = geom_type(header.code);
+        let mut coordinates = vec![];
+        let mut geometries = vec![];
+
+        match header.code {
+            WKB_POINT => {
+                let pt = self.point(order, dim)?;
+                //empty point is encoded as NaN coordinates
+                if pt.x.is_nan() && pt.y.is_nan() {
+                    return Err(GeomError::TooFewCoordinates { expected: 1, found: 0 });
+                }
+                coordinates.push(vec![pt]);
+            }
+            WKB_LINESTRING => {
+                coordinates.push(self.coordinates(order, dim)?);
+            }
+            WKB_POLYGON => {
+                let n = self.count(order, 4)?;
+                for _ in 0..n {
+                    coordinates.push(self.coordinates(order, dim)?);
+                }
+            }
+            _ => {
+                let n = self.count(order, 5)?;
+                for _ in 0..n {
+                    let part = self.geometry(depth + 1)?;
+                    let valid_part = match header.code {
+                        WKB_MULTIPOINT => part.geom_type == GeomType::Point,
+                        WKB_MULTILINESTRING => part.geom_type == GeomType::LineString,
+                        WKB_MULTIPOLYGON => part.geom_type == GeomType::Polygon,
+                        _ => true,
+                    };
+                    if !valid_part {
+                        return Err(GeomError::WrongGeomType {
+                            expected: single_type(geom_type),
+                            found: part.geom_type,
+                        });
+                    }
+                    geometries.push(part);
+                }
+            }
+        }
+
+        //multi point and multi line string keep parts as coordinates, see wkt::parse_wkt
+        match geom_type {
+            GeomType::MultiPoint => {
+                coordinates.push(geometries.drain(..).map(|g| g.coordinates[0][0]).collect());
+            }
+            GeomType::MultiLineString => {
+                coordinates = geometries.drain(..).map(|mut g| g.coordinates.remove(0)).collect();
+            }
+            _ => {}
+        }
+        Ok(WKT { geom_type, coordinates, geometries })
+    }
+}
+
+fn single_type(geom_type: GeomType) -> GeomType {
+    match geom_type {
+        GeomType::MultiPoint => GeomType::Point,
+        GeomType::MultiLineString => GeomType::LineString,
+        GeomType::MultiPolygon => GeomType::Polygon,
+        _ => geom_type,
+    }
+}
+
+#[cfg(test)]
+mod tests;
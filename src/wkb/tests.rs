@@ -0,0 +1,168 @@
+use super::*;
+use crate::{
+    LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon,
+    GeometryCollection, Segment, read_wkt,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_wkb_point() {
+    let pt = Point::new(1., 2.);
+    let le = hex("0101000000000000000000F03F0000000000000040");
+    let be = hex("00000000013FF00000000000004000000000000000");
+    assert_eq!(pt.wkb_bytes(ByteOrder::LittleEndian), le);
+    assert_eq!(pt.wkb_bytes(ByteOrder::BigEndian), be);
+    assert_eq!(Point::from_wkb(&le).unwrap(), pt);
+    assert_eq!(Point::from_wkb(&be).unwrap(), pt);
+    let seg = Segment::new(pt, Point::new(3., 4.));
+    assert_eq!(LineString::from_wkb(&seg.wkb_bytes(ByteOrder::BigEndian)).unwrap().wkt(), seg.wkt());
+}
+
+#[test]
+fn test_wkb_round_trip() {
+    let geoms = vec![
+        "POINT(3 4)",
+        "LINESTRING(0 0,5 5,7.5 -1)",
+        "POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,2 8,8 8,8 2,2 2))",
+        "MULTIPOINT((30 30),(31 31))",
+        "MULTILINESTRING((0 0,5 5),(10 0,10 5))",
+        "MULTIPOLYGON(((0 0,0 10,10 10,10 0,0 0),(2 2,2 8,8 8,8 2,2 2)),((20 0,20 5,25 5,25 0,20 0)))",
+        "GEOMETRYCOLLECTION(POINT(3 4),GEOMETRYCOLLECTION(LINESTRING(0 0,5 5)))",
+    ];
+    for order in [ByteOrder::LittleEndian, ByteOrder::BigEndian].iter() {
+        for wkt in geoms.iter() {
+            let g = read_wkt(wkt).unwrap();
+            let buf = g.wkb_bytes(*order);
+            assert_eq!(read_wkb(&buf).unwrap().wkt_string(), *wkt);
+        }
+    }
+    let ply = Polygon::from_wkt(geoms[2]);
+    assert_eq!(Polygon::from_wkb(&ply.wkb_bytes(ByteOrder::LittleEndian)).unwrap().wkt(), geoms[2]);
+    let mpt = MultiPoint::from_wkt(geoms[3]);
+    assert_eq!(MultiPoint::from_wkb(&mpt.wkb_bytes(ByteOrder::BigEndian)).unwrap().wkt(), geoms[3]);
+    let mln = MultiLineString::from_wkt(geoms[4]);
+    assert_eq!(MultiLineString::from_wkb(&mln.wkb_bytes(ByteOrder::BigEndian)).unwrap().wkt(), geoms[4]);
+    let mply = MultiPolygon::from_wkt(geoms[5]);
+    assert_eq!(MultiPolygon::from_wkb(&mply.wkb_bytes(ByteOrder::LittleEndian)).unwrap().wkt(), geoms[5]);
+    let gc = GeometryCollection::from_wkt(geoms[6]);
+    assert_eq!(GeometryCollection::from_wkb(&gc.wkb_bytes(ByteOrder::LittleEndian)).unwrap().wkt(), geoms[6]);
+    assert_eq!(
+        Point::from_wkb(&mpt.wkb_bytes(ByteOrder::LittleEndian)).unwrap_err(),
+        GeomError::WrongGeomType { expected: GeomType::Point, found: GeomType::MultiPoint }
+    );
+}
+
+#[test]
+fn test_wkb_z() {
+    let pt = PointZ::new(1., 2., 3.);
+    let iso = hex("01E9030000000000000000F03F00000000000000400000000000000840");
+    let ewkb = hex("0101000080000000000000F03F00000000000000400000000000000840");
+    assert_eq!(pt.wkb(ByteOrder::LittleEndian, Dialect::Iso), iso);
+    assert_eq!(pt.wkb(ByteOrder::LittleEndian, Dialect::Ewkb), ewkb);
+    assert_eq!(PointZ::from_wkb(&iso).unwrap(), pt);
+    assert_eq!(PointZ::from_wkb(&ewkb).unwrap(), pt);
+    let be = pt.wkb(ByteOrder::BigEndian, Dialect::Ewkb);
+    assert_eq!(PointZ::from_wkb(&be).unwrap(), pt);
+    //z dropped when read as 2d point
+    assert_eq!(Point::from_wkb(&iso).unwrap(), Point::new(1., 2.));
+    assert_eq!(
+        PointZ::from_wkb(&Point::new(1., 2.).wkb_bytes(ByteOrder::LittleEndian)).unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 3, found: 2 }
+    );
+
+    //EWKB linestring z with srid 4326
+    let mut buf = vec![];
+    write_header(&mut buf, ByteOrder::LittleEndian, WKB_LINESTRING | EWKB_Z | EWKB_SRID);
+    write_u32(&mut buf, ByteOrder::LittleEndian, 4326);
+    write_u32(&mut buf, ByteOrder::LittleEndian, 2);
+    for v in [0., 0., 9., 5., 5., 9.].iter() {
+        write_f64(&mut buf, ByteOrder::LittleEndian, *v);
+    }
+    assert_eq!(LineString::from_wkb(&buf).unwrap().wkt(), "LINESTRING(0 0,5 5)");
+
+    //ISO linestring zm
+    let mut buf = vec![];
+    write_header(&mut buf, ByteOrder::BigEndian, WKB_LINESTRING + ISO_ZM);
+    write_u32(&mut buf, ByteOrder::BigEndian, 2);
+    for v in [0., 0., 9., 1., 5., 5., 9., 1.].iter() {
+        write_f64(&mut buf, ByteOrder::BigEndian, *v);
+    }
+    assert_eq!(LineString::from_wkb(&buf).unwrap().wkt(), "LINESTRING(0 0,5 5)");
+}
+
+#[test]
+fn test_wkb_errors() {
+    let le = hex("0101000000000000000000F03F0000000000000040");
+    assert_eq!(
+        parse_wkb(&le[..15]).unwrap_err(),
+        GeomError::Parse { message: "unexpected end of wkb buffer".into(), position: 13 }
+    );
+    assert_eq!(
+        parse_wkb(&[]).unwrap_err(),
+        GeomError::Parse { message: "unexpected end of wkb buffer".into(), position: 0 }
+    );
+    assert_eq!(
+        parse_wkb(&hex("0201000000")).unwrap_err(),
+        GeomError::Parse { message: "invalid wkb byte order".into(), position: 0 }
+    );
+    assert_eq!(
+        parse_wkb(&hex("0109000000")).unwrap_err(),
+        GeomError::Unsupported { geom: "wkb type 9".into() }
+    );
+    assert_eq!(
+        parse_wkb(&hex("0102000000FFFFFFFF00000000")).unwrap_err(),
+        GeomError::Parse { message: "element count exceeds wkb buffer".into(), position: 9 }
+    );
+    let mut buf = vec![];
+    write_point(&mut buf, ByteOrder::LittleEndian, &Point::new(std::f64::NAN, std::f64::NAN));
+    assert_eq!(buf, hex("0101000000000000000000F87F000000000000F87F"));
+    assert_eq!(
+        parse_wkb(&buf).unwrap_err(),
+        GeomError::TooFewCoordinates { expected: 1, found: 0 }
+    );
+    //multipoint holding a linestring
+    let mut buf = vec![];
+    write_header(&mut buf, ByteOrder::LittleEndian, WKB_MULTIPOINT);
+    write_u32(&mut buf, ByteOrder::LittleEndian, 1);
+    write_linestring(&mut buf, ByteOrder::LittleEndian, &[Point::new(0., 0.), Point::new(1., 1.)]);
+    assert_eq!(
+        parse_wkb(&buf).unwrap_err(),
+        GeomError::WrongGeomType { expected: GeomType::Point, found: GeomType::LineString }
+    );
+    //unclosed polygon ring
+    let mut buf = vec![];
+    write_header(&mut buf, ByteOrder::LittleEndian, WKB_POLYGON);
+    write_u32(&mut buf, ByteOrder::LittleEndian, 1);
+    write_u32(&mut buf, ByteOrder::LittleEndian, 4);
+    for v in [0., 0., 0., 1., 1., 1., 1., 0.].iter() {
+        write_f64(&mut buf, ByteOrder::LittleEndian, *v);
+    }
+    assert_eq!(Polygon::from_wkb(&buf).unwrap_err(), GeomError::UnclosedRing { ring: 0 });
+}
+
+#[test]
+fn test_wkb_nesting_limit() {
+    //point inside n collections, each header holding one part
+    let nested = |n: usize| {
+        let mut buf = vec![];
+        for _ in 0..n {
+            write_header(&mut buf, ByteOrder::LittleEndian, WKB_GEOMETRYCOLLECTION);
+            write_u32(&mut buf, ByteOrder::LittleEndian, 1);
+        }
+        write_point(&mut buf, ByteOrder::LittleEndian, &Point::new(3., 4.));
+        buf
+    };
+    let gc = GeometryCollection::from_wkb(&nested(MAX_NESTING - 1)).unwrap();
+    assert_eq!(gc.geometries().len(), 1);
+    assert_eq!(
+        parse_wkb(&nested(MAX_NESTING)).unwrap_err(),
+        GeomError::Parse { message: "wkb nested deeper than 64 levels".into(), position: 64 * 9 }
+    );
+    //deep enough to overflow the stack if parsed without a limit
+    assert!(parse_wkb(&nested(200_000)).is_err());
+}
@@ -1,67 +1,224 @@
-use crate::{GeomType, Point};
+use crate::{
+    GeomType, GeomError, Geometry, Point, LineString, Polygon,
+    MultiPoint, MultiLineString, MultiPolygon, GeometryCollection,
+};
+use std::convert::TryFrom;
 
 #[derive(Clone, Debug)]
 pub struct WKT {
     pub geom_type: GeomType,
     pub coordinates: Vec<Vec<Point>>,
-    pub success: bool,
-    pub message: String,
+    pub geometries: Vec<WKT>,
 }
 
-pub fn parse_wkt(s: &str) -> WKT {
-    let geom_type;
-    let mut wkt;
-    let res = wkt::Wkt::from_str(s);
-    match res {
-        Ok(o) => wkt = o,
-        Err(err) => {
-            return WKT {
-                geom_type: GeomType::Unknown,
-                coordinates: vec![],
-                success: false,
-                message: err.into(),
-            };
-        }
+///Deepest nesting accepted from wkt brackets or wkb headers, deeper input is
+///rejected before it can exhaust the stack
+pub const MAX_NESTING: usize = 64;
+
+pub fn parse_wkt(s: &str) -> Result<WKT, GeomError> {
+    let mut rd = Reader::new(s);
+    if rd.at_end() {
+        return Err(rd.error("empty wkt string"));
+    }
+    let o = rd.geometry(1)?;
+    if !rd.at_end() {
+        return Err(rd.error("unexpected text after geometry"));
+    }
+    Ok(o)
+}
+
+///Parse wkt string of any geometry type
+pub fn read_wkt(s: &str) -> Result<Box<dyn Geometry>, GeomError> {
+    to_geometry(parse_wkt(s)?)
+}
+
+///Converts parsed wkt to a geometry of the parsed type
+pub fn to_geometry(o: WKT) -> Result<Box<dyn Geometry>, GeomError> {
+    let geom: Box<dyn Geometry> = match o.geom_type {
+        GeomType::Point => Box::new(Point::try_from(o)?),
+        GeomType::LineString => Box::new(LineString::try_from(o)?),
+        GeomType::Polygon => Box::new(Polygon::try_from(o)?),
+        GeomType::MultiPoint => Box::new(MultiPoint::try_from(o)?),
+        GeomType::MultiLineString => Box::new(MultiLineString::try_from(o)?),
+        GeomType::MultiPolygon => Box::new(MultiPolygon::try_from(o)?),
+        GeomType::GeometryCollection => Box::new(GeometryCollection::try_from(o)?),
+        _ => return Err(GeomError::Unsupported { geom: format!("{}", o.geom_type) }),
     };
+    Ok(geom)
+}
+
+//recursive descent over wkt text, errors are reported at the byte offset they occur
+struct Reader<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(s: &'a str) -> Reader<'a> {
+        Reader { s, pos: 0 }
+    }
 
-    let extract_coordinates = |coords: &Vec<wkt::types::Coord<f64>>| {
-        let mut shell = vec![];
-        for c in coords.iter() {
-            shell.push(Point::new(c.x, c.y));
+    fn error(&self, message: &str) -> GeomError {
+        GeomError::Parse { message: message.into(), position: self.pos }
+    }
+
+    fn skip_space(&mut self) {
+        let rest = &self.s[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_space();
+        self.pos == self.s.len()
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_space();
+        self.s[self.pos..].chars().next()
+    }
+
+    //consumes c if it is the next character
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += c.len_utf8();
         }
-        shell
-    };
+        found
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), GeomError> {
+        if self.eat(c) { Ok(()) } else { Err(self.error(&format!("expected `{}`", c))) }
+    }
 
-    let coordinates = match wkt.items.pop().unwrap() {
-        wkt::Geometry::Point(wkt::types::Point(coords)) => {
-            geom_type = GeomType::Point;
-            let c = coords.unwrap();
-            vec![vec![Point::new(c.x, c.y)]]
+    //next run of letters, upper cased, empty if none
+    fn word(&mut self) -> String {
+        self.skip_space();
+        let rest = &self.s[self.pos..];
+        let n = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+        self.pos += n;
+        rest[..n].to_ascii_uppercase()
+    }
+
+    //consumes the keyword EMPTY if it comes next
+    fn empty(&mut self) -> bool {
+        let pos = self.pos;
+        if self.word() == "EMPTY" {
+            return true;
         }
-        wkt::Geometry::LineString(wkt::types::LineString(coords)) => {
-            geom_type = GeomType::LineString;
-            let shell = extract_coordinates(&coords);
-            vec![shell]
+        self.pos = pos;
+        false
+    }
+
+    fn number(&mut self) -> Result<f64, GeomError> {
+        self.skip_space();
+        let rest = &self.s[self.pos..];
+        let n = rest.find(|c: char| !(c.is_ascii_digit() || "+-.eE".contains(c))).unwrap_or(rest.len());
+        match rest[..n].parse::<f64>() {
+            Ok(v) if n > 0 => {
+                self.pos += n;
+                Ok(v)
+            }
+            _ => Err(self.error("expected a number")),
         }
-        wkt::Geometry::Polygon(wkt::types::Polygon(lines)) => {
-            geom_type = GeomType::Polygon;
-            let mut shells = vec![];
-            for ln in lines {
-                let shell = extract_coordinates(&ln.0);
-                shells.push(shell);
+    }
+
+    //x y, z and m are read and dropped
+    fn coord(&mut self) -> Result<Point, GeomError> {
+        let x = self.number()?;
+        let y = self.number()?;
+        for _ in 0..2 {
+            match self.peek() {
+                Some(c) if c.is_ascii_digit() || "+-.".contains(c) => { self.number()?; }
+                _ => break,
             }
-            shells
         }
-        _ => unreachable!(),
-    };
+        Ok(Point::new(x, y))
+    }
 
-    WKT {
-        geom_type,
-        coordinates,
-        success: true,
-        message: String::new(),
+    //comma separated items within brackets
+    fn list<T, F>(&mut self, mut item: F) -> Result<Vec<T>, GeomError>
+        where F: FnMut(&mut Reader<'a>) -> Result<T, GeomError> {
+        self.expect('(')?;
+        let mut items = vec![item(self)?];
+        while self.eat(',') {
+            items.push(item(self)?);
+        }
+        if !self.eat(')') {
+            return Err(self.error("expected `,` or `)`"));
+        }
+        Ok(items)
     }
-}
 
+    fn coords(&mut self) -> Result<Vec<Point>, GeomError> {
+        self.list(|rd| rd.coord())
+    }
+
+    fn rings(&mut self) -> Result<Vec<Vec<Point>>, GeomError> {
+        self.list(|rd| rd.coords())
+    }
 
+    //multipoint members with or without their own brackets
+    fn multi_point(&mut self) -> Result<Vec<Point>, GeomError> {
+        self.list(|rd| if rd.eat('(') {
+            let pt = rd.coord()?;
+            rd.expect(')')?;
+            Ok(pt)
+        } else {
+            rd.coord()
+        })
+    }
 
+    fn geometry(&mut self, depth: usize) -> Result<WKT, GeomError> {
+        self.skip_space();
+        if depth > MAX_NESTING {
+            return Err(self.error(&format!("nesting deeper than {} levels", MAX_NESTING)));
+        }
+        let start = self.pos;
+        let name = self.word();
+        let geom_type = match name.as_str() {
+            "POINT" => GeomType::Point,
+            "LINESTRING" => GeomType::LineString,
+            "POLYGON" => GeomType::Polygon,
+            "MULTIPOINT" => GeomType::MultiPoint,
+            "MULTILINESTRING" => GeomType::MultiLineString,
+            "MULTIPOLYGON" => GeomType::MultiPolygon,
+            "GEOMETRYCOLLECTION" => GeomType::GeometryCollection,
+            _ => {
+                self.pos = start;
+                return Err(self.error(&format!("unknown geometry type : {}", name)));
+            }
+        };
+        //dimension tag, extra ordinates are dropped
+        let pos = self.pos;
+        if !matches!(self.word().as_str(), "Z" | "M" | "ZM") {
+            self.pos = pos;
+        }
+        let empty = self.empty();
+        let mut geometries = vec![];
+        let coordinates = match geom_type {
+            GeomType::Point if empty => vec![],
+            GeomType::Point => {
+                self.expect('(')?;
+                let pt = self.coord()?;
+                self.expect(')')?;
+                vec![vec![pt]]
+            }
+            GeomType::LineString | GeomType::MultiPoint if empty => vec![vec![]],
+            GeomType::LineString => vec![self.coords()?],
+            GeomType::MultiPoint => vec![self.multi_point()?],
+            _ if empty => vec![],
+            GeomType::Polygon | GeomType::MultiLineString => self.rings()?,
+            GeomType::MultiPolygon => {
+                for coordinates in self.list(|rd| rd.rings())? {
+                    geometries.push(WKT { geom_type: GeomType::Polygon, coordinates, geometries: vec![] });
+                }
+                vec![]
+            }
+            _ => {
+                geometries = self.list(|rd| rd.geometry(depth + 1))?;
+                vec![]
+            }
+        };
+        Ok(WKT { geom_type, coordinates, geometries })
+    }
+}